@@ -0,0 +1,347 @@
+//! Compile the site configuration, the short links, the updates and the bibliography from
+//! `content/` into static Rust data.
+//!
+//! The parsers are shared with the app, and malformed content fails the build with the
+//! location of the error, e.g. `content/updates.yaml:12:9: ...`. The build script also enables
+//! the `hot_reload` cfg for debug builds of the native app with the `hot-reload` feature.
+
+use std::{borrow::Cow, fmt::Write as _, path::Path};
+
+#[allow(dead_code)]
+#[path = "src/site/config.rs"]
+mod config;
+#[allow(dead_code)]
+#[path = "src/content/model.rs"]
+mod model;
+#[allow(dead_code)]
+#[path = "src/content/parse.rs"]
+mod parse;
+#[allow(dead_code)]
+#[path = "src/navigation/redirects.rs"]
+mod redirects;
+#[allow(dead_code)]
+#[path = "src/site/validate.rs"]
+mod validate;
+
+use config::{AuthorConfig, Banner, PagesConfig, SiteConfig, Social, SocialLink};
+use model::{Person, Publication, PublicationDate, PublicationDetails, PublicationLinks, Update};
+use redirects::{Redirect, RedirectTarget};
+
+fn main() {
+    // Debug builds of the native app can reload the content from disk when it changes, unless
+    // the content is fetched from the server instead
+    println!("cargo::rustc-check-cfg=cfg(hot_reload)");
+    if std::env::var_os("CARGO_FEATURE_HOT_RELOAD").is_some()
+        && std::env::var_os("CARGO_CFG_DEBUG_ASSERTIONS").is_some()
+        && std::env::var("CARGO_CFG_TARGET_ARCH").is_ok_and(|arch| arch != "wasm32")
+        && std::env::var_os("CARGO_FEATURE_REMOTE_CONTENT").is_none()
+    {
+        println!("cargo::rustc-cfg=hot_reload");
+    }
+
+    let out_dir = std::env::var_os("OUT_DIR").expect("OUT_DIR is set by Cargo");
+    let write = |file_name: &str, code: String| {
+        std::fs::write(Path::new(&out_dir).join(file_name), code)
+            .expect("Failed to write the generated content");
+    };
+
+    let site = parse_content("site.yaml", SiteConfig::parse);
+    write(
+        "site.rs",
+        format!(
+            "/// Configuration of the site from `content/site.yaml`.\nstatic SITE: SiteConfig = {};",
+            site.literal()
+        ),
+    );
+
+    let redirects = parse_content("redirects.yaml", parse::yaml::<Vec<Redirect>>);
+    write(
+        "redirects.rs",
+        format!(
+            "/// Short links from `content/redirects.yaml`.\nstatic REDIRECTS: Redirects = Redirects::new({});",
+            redirects.as_slice().literal()
+        ),
+    );
+
+    let mut code = String::new();
+
+    let updates = parse_content("updates.yaml", parse::updates);
+    writeln!(
+        code,
+        "/// Updates from `content/updates.yaml`, newest first.\npub static UPDATES: &[Update] = {};",
+        updates.as_slice().literal()
+    )
+    .unwrap();
+
+    if std::env::var_os("CARGO_FEATURE_BIBLIOGRAPHY").is_some() {
+        let path = "publications/bibliography.bib";
+        let publications = all_entries(path, parse_content(path, parse::publications));
+        writeln!(
+            code,
+            "/// Publications from `content/publications/bibliography.bib` in the order of the source.\npub static PUBLICATIONS: &[Publication] = {};",
+            publications.as_slice().literal()
+        )
+        .unwrap();
+    }
+
+    write("content.rs", code);
+}
+
+/// Read and parse a file from `content/`, or fail the build with the location of the error.
+fn parse_content<T, E: std::fmt::Display>(
+    path: &str,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> T {
+    let path = Path::new("content").join(path);
+    println!("cargo::rerun-if-changed={}", path.display());
+
+    let src = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| fail(format_args!("{}: {err}", path.display())));
+    parse(&src).unwrap_or_else(|err| fail(format_args!("{}:{err}", path.display())))
+}
+
+/// Unwrap the parsed entries of a file, or fail the build with the locations of all malformed
+/// entries.
+fn all_entries<T>(path: &str, entries: Vec<Result<T, parse::ParseError>>) -> Vec<T> {
+    let mut errors = Vec::new();
+    let entries = entries
+        .into_iter()
+        .filter_map(|entry| entry.map_err(|err| errors.push(err)).ok())
+        .collect();
+    if errors.is_empty() {
+        return entries;
+    }
+    for err in &errors {
+        eprintln!("error: content/{path}:{err}");
+    }
+    std::process::exit(1);
+}
+
+fn fail(message: std::fmt::Arguments) -> ! {
+    eprintln!("error: {message}");
+    std::process::exit(1);
+}
+
+/// Rust expression that constructs the value within a `static` item.
+trait Literal {
+    fn literal(&self) -> String;
+}
+
+impl Literal for Cow<'static, str> {
+    fn literal(&self) -> String {
+        // The debug representation of a string is a valid Rust string literal
+        format!("Cow::Borrowed({:?})", self.as_ref())
+    }
+}
+
+impl<T: Literal + Clone> Literal for Cow<'static, [T]> {
+    fn literal(&self) -> String {
+        format!("Cow::Borrowed({})", self.as_ref().literal())
+    }
+}
+
+impl<T: Literal> Literal for [T] {
+    fn literal(&self) -> String {
+        let items = self
+            .iter()
+            .map(|item| item.literal() + ",\n")
+            .collect::<String>();
+        format!("&[\n{items}]")
+    }
+}
+
+impl<T: Literal> Literal for Option<T> {
+    fn literal(&self) -> String {
+        self.as_ref().map_or_else(
+            || "None".to_owned(),
+            |value| format!("Some({})", value.literal()),
+        )
+    }
+}
+
+impl Literal for i32 {
+    fn literal(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Literal for u8 {
+    fn literal(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Literal for chrono::NaiveDate {
+    fn literal(&self) -> String {
+        use chrono::Datelike as _;
+        format!(
+            "chrono::NaiveDate::from_ymd_opt({}, {}, {}).unwrap()",
+            self.year(),
+            self.month(),
+            self.day()
+        )
+    }
+}
+
+impl Literal for Update {
+    fn literal(&self) -> String {
+        format!(
+            "Update {{ date: {}, title: {}, highlights: {}, description: {}, url: {} }}",
+            self.date.literal(),
+            self.title.literal(),
+            self.highlights.literal(),
+            self.description.literal(),
+            self.url.literal()
+        )
+    }
+}
+
+impl Literal for Publication {
+    fn literal(&self) -> String {
+        format!(
+            "Publication {{ key: {}, entry_type: {}, title: {}, authors: {}, date: {}, venue: {}, details: {}, summary: {}, keywords: {}, links: {}, bibtex: {} }}",
+            self.key.literal(),
+            self.entry_type.literal(),
+            self.title.literal(),
+            self.authors.literal(),
+            self.date.literal(),
+            self.venue.literal(),
+            self.details.literal(),
+            self.summary.literal(),
+            self.keywords.literal(),
+            self.links.literal(),
+            self.bibtex.literal()
+        )
+    }
+}
+
+impl Literal for Person {
+    fn literal(&self) -> String {
+        format!(
+            "Person {{ given_name: {}, name: {} }}",
+            self.given_name.literal(),
+            self.name.literal()
+        )
+    }
+}
+
+impl Literal for PublicationDate {
+    fn literal(&self) -> String {
+        format!(
+            "PublicationDate {{ year: {}, month: {}, day: {} }}",
+            self.year.literal(),
+            self.month.literal(),
+            self.day.literal()
+        )
+    }
+}
+
+impl Literal for PublicationDetails {
+    fn literal(&self) -> String {
+        format!(
+            "PublicationDetails {{ volume: {}, number: {}, pages: {}, publisher: {}, doi: {} }}",
+            self.volume.literal(),
+            self.number.literal(),
+            self.pages.literal(),
+            self.publisher.literal(),
+            self.doi.literal()
+        )
+    }
+}
+
+impl Literal for PublicationLinks {
+    fn literal(&self) -> String {
+        format!(
+            "PublicationLinks {{ homepage: {}, publication_url: {}, article_pdf: {}, article_html: {}, video: {}, presentation: {}, repository: {} }}",
+            self.homepage.literal(),
+            self.publication_url.literal(),
+            self.article_pdf.literal(),
+            self.article_html.literal(),
+            self.video.literal(),
+            self.presentation.literal(),
+            self.repository.literal()
+        )
+    }
+}
+
+impl Literal for SiteConfig {
+    fn literal(&self) -> String {
+        format!(
+            "SiteConfig {{ author: {}, pages: {}, socials: {}, banners: {} }}",
+            self.author.literal(),
+            self.pages.literal(),
+            self.socials.literal(),
+            self.banners.literal()
+        )
+    }
+}
+
+impl Literal for AuthorConfig {
+    fn literal(&self) -> String {
+        format!(
+            "AuthorConfig {{ name: {}, short_name: {}, surname: {}, initials: {}, title: {} }}",
+            self.name.literal(),
+            self.short_name.literal(),
+            self.surname.literal(),
+            self.initials.literal(),
+            self.title.literal()
+        )
+    }
+}
+
+impl Literal for PagesConfig {
+    fn literal(&self) -> String {
+        format!(
+            "PagesConfig {{ enabled: {}, separate_menu_at: {} }}",
+            self.enabled.literal(),
+            self.separate_menu_at.literal()
+        )
+    }
+}
+
+impl Literal for SocialLink {
+    fn literal(&self) -> String {
+        format!(
+            "SocialLink {{ kind: {}, url: {} }}",
+            self.kind.literal(),
+            self.url.literal()
+        )
+    }
+}
+
+impl Literal for Social {
+    fn literal(&self) -> String {
+        format!("Social::{self:?}")
+    }
+}
+
+impl Literal for Banner {
+    fn literal(&self) -> String {
+        format!(
+            "Banner {{ id: {}, text: {}, url: {} }}",
+            self.id.literal(),
+            self.text.literal(),
+            self.url.literal()
+        )
+    }
+}
+
+impl Literal for Redirect {
+    fn literal(&self) -> String {
+        format!(
+            "Redirect {{ slug: {}, target: {}, expires: {} }}",
+            self.slug.literal(),
+            self.target.literal(),
+            self.expires.literal()
+        )
+    }
+}
+
+impl Literal for RedirectTarget {
+    fn literal(&self) -> String {
+        match self {
+            Self::Url(url) => format!("RedirectTarget::Url({})", url.literal()),
+            Self::Route(route) => format!("RedirectTarget::Route({})", route.literal()),
+        }
+    }
+}
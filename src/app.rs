@@ -1,26 +1,16 @@
-use crate::page::Page;
+use itertools::Itertools;
 
-#[derive(serde::Deserialize, serde::Serialize)]
-#[serde(default)]
-pub struct App {
-    theme: egui::Theme,
-    #[serde(skip)]
-    current_page: Page,
-    #[serde(skip)]
-    pages: rustc_hash::FxHashMap<Page, Box<dyn eframe::App>>,
-}
+use crate::{
+    navigation::Navigator,
+    page::{LazyPages, NotFoundPage},
+    persistence::PersistedState,
+};
 
-impl Default for App {
-    fn default() -> Self {
-        Self {
-            theme: egui::Theme::Dark,
-            current_page: Page::default(),
-            pages: crate::ENABLED_PAGES
-                .into_iter()
-                .map(|page| (page, page.default_app()))
-                .collect(),
-        }
-    }
+pub struct App {
+    state: PersistedState,
+    navigator: Navigator,
+    pages: LazyPages,
+    not_found: Option<NotFoundPage>,
 }
 
 impl App {
@@ -35,17 +25,39 @@ impl App {
         // // Enable screen web reader support
         // cc.egui_ctx.options_mut(|o| o.screen_reader = true);
 
-        // Construct the app state
-        let app = if let Some(storage) = cc.storage {
-            // Try to restore previous state
-            eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default()
-        } else {
-            // Otherwise, use default state
-            Self::default()
+        // Restore the state of the previous visit
+        let mut state = PersistedState::restore(cc.storage);
+        let app = Self {
+            navigator: Navigator::with_scroll_offsets(std::mem::take(&mut state.scroll_offsets)),
+            pages: LazyPages::default(),
+            not_found: None,
+            state,
         };
 
-        // Set the theme
-        crate::style::set_theme(&cc.egui_ctx, app.theme);
+        // Set the theme and the scale of the UI
+        crate::style::set_theme(&cc.egui_ctx, app.state.theme);
+        if app.state.ui_scale.is_finite() && app.state.ui_scale > 0.0 {
+            cc.egui_ctx.set_zoom_factor(app.state.ui_scale);
+        }
+
+        // The native app continues where it was left, whereas the web app follows its URL
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(route) = app
+            .state
+            .last_route
+            .as_deref()
+            .and_then(crate::navigation::Route::parse)
+        {
+            crate::navigation::open_route(&cc.egui_ctx, route, true);
+        }
+
+        // Prepare the navigation between pages (the route requested on the command line takes
+        // precedence over the last one)
+        crate::navigation::init(&cc.egui_ctx);
+
+        // Reload the content from disk whenever it changes
+        #[cfg(hot_reload)]
+        crate::utils::hot_reload::init(&cc.egui_ctx);
 
         app
     }
@@ -53,30 +65,19 @@ impl App {
 
 impl eframe::App for App {
     fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
-        // Synchronize the page URL and content if the URL contains a hash
-        #[cfg(target_arch = "wasm32")]
-        if let Some(page) = frame.info().web_info.location.hash.strip_prefix('#') {
-            if let Some(page) = crate::ENABLED_PAGES
-                .into_iter()
-                .find(|x| x.to_string().eq_ignore_ascii_case(page))
-            {
-                // If a known page was requested, update the current page
-                self.current_page = page;
+        // Apply pending navigation requests and keep the URL in sync with the current page
+        self.navigator.update(ctx);
 
-                // If the page is a redirect, redirect to it
-                if self.current_page.redirect_page().is_some() {
-                    crate::utils::egui::open_url_on_page(ctx, self.current_page, true);
-                }
-            } else {
-                // If an unknown page was requested, update the URL to open the default page
-                crate::utils::egui::open_url_on_page(ctx, Page::default(), true);
-            }
-        } else {
-            // Otherwise, update the URL to match the current page
-            if self.current_page != Page::default() {
-                crate::utils::egui::open_url_on_page(ctx, self.current_page, true);
-            }
-        }
+        // Remember the scale of the UI (e.g. after zooming via Ctrl+Plus/Minus)
+        self.state.ui_scale = ctx.zoom_factor();
+
+        // Let the author know about content that failed to reload
+        #[cfg(hot_reload)]
+        crate::utils::hot_reload::show_errors(ctx);
+
+        // Let the visitor know where an exported file was saved
+        #[cfg(feature = "bibliography")]
+        crate::utils::download::show_status(ctx);
 
         // Navigation panel that allows switching between page
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
@@ -85,7 +86,7 @@ impl eframe::App for App {
 
                 // Navigation
                 {
-                    if screen_width >= self.pages.len() as f32 * 175.0 {
+                    if screen_width >= crate::page::registry().len() as f32 * 175.0 {
                         // Horizontal menu
                         ui.style_mut().override_text_style =
                             Some(egui::TextStyle::Name("navigation_panel_display".into()));
@@ -108,12 +109,12 @@ impl eframe::App for App {
 
                 // Socials
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    if screen_width >= crate::ENABLED_SOCIALS.len() as f32 * 48.0 {
+                    if screen_width >= crate::site().socials.len() as f32 * 48.0 {
                         // Buttons
                         ui.style_mut().override_text_style =
                             Some(egui::TextStyle::Name("social".into()));
                         ui.spacing_mut().item_spacing.x = 8.0;
-                        for social in crate::ENABLED_SOCIALS {
+                        for social in crate::site().socials.iter() {
                             social.show(ui);
                         }
                     } else {
@@ -125,7 +126,7 @@ impl eframe::App for App {
                                 ui.style_mut().override_text_style =
                                     Some(egui::TextStyle::Name("social".into()));
                                 ui.spacing_mut().item_spacing.y = 8.0;
-                                for social in crate::ENABLED_SOCIALS.into_iter().rev() {
+                                for social in crate::site().socials.iter().rev() {
                                     social.show(ui);
                                 }
                             });
@@ -135,6 +136,9 @@ impl eframe::App for App {
             });
         });
 
+        // Announcements that were not dismissed yet
+        self.show_banners(ctx);
+
         // Bottom panel
         egui::TopBottomPanel::bottom("bottom_panel").show(ctx, |ui| {
             ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
@@ -147,16 +151,31 @@ impl eframe::App for App {
             });
         });
 
-        // Update the current page
-        for page in crate::ENABLED_PAGES {
-            if self.current_page == page || ctx.memory(egui::Memory::everything_is_visible) {
-                self.pages
-                    .get_mut(&page)
-                    .unwrap()
-                    .as_mut()
-                    .update(ctx, frame);
+        // Let the visitor know if the requested path does not exist
+        if let Some(path) = self.navigator.unknown_path() {
+            if self
+                .not_found
+                .as_ref()
+                .is_none_or(|not_found| not_found.path() != path)
+            {
+                self.not_found = Some(NotFoundPage::new(path));
+            }
+            if let Some(not_found) = &mut self.not_found {
+                not_found.update(ctx, frame);
+            }
+            return;
+        }
+
+        // Update the current page (or the previous one during a transition)
+        let visible_page = self.navigator.visible_page();
+        for &page in crate::page::registry() {
+            if visible_page == Some(page) || ctx.memory(egui::Memory::everything_is_visible) {
+                self.pages.get_or_init(page).update(ctx, frame);
             }
         }
+
+        // Prepare the remaining pages in the background
+        self.pages.init_next_when_idle(ctx);
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -165,19 +184,49 @@ impl eframe::App for App {
     }
 
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
-        eframe::set_value(storage, eframe::APP_KEY, self);
+        self.state.last_route = self.navigator.current_route().map(ToString::to_string);
+        self.state.scroll_offsets = self.navigator.scroll_offsets().clone();
+        self.state.save(storage);
     }
 }
 
 impl App {
+    fn show_banners(&mut self, ctx: &egui::Context) {
+        let banners = crate::site()
+            .banners
+            .iter()
+            .filter(|banner| !self.state.dismissed_banners.contains(banner.id.as_ref()))
+            .collect_vec();
+        if banners.is_empty() {
+            return;
+        }
+
+        egui::TopBottomPanel::top("banner_panel").show(ctx, |ui| {
+            for banner in banners {
+                ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                    if let Some(url) = &banner.url {
+                        crate::utils::egui::clickable_url(ui.link(banner.text.as_ref()), url);
+                    } else {
+                        ui.label(banner.text.as_ref());
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("\u{e5cd}").on_hover_text("Dismiss").clicked() {
+                            self.state.dismissed_banners.insert(banner.id.to_string());
+                        }
+                    });
+                });
+            }
+        });
+    }
+
     fn navigation_buttons(&mut self, ui: &mut egui::Ui) {
-        for page in crate::ENABLED_PAGES {
+        for &page in crate::page::registry() {
             // Add a separator if requested
-            if crate::SEPARATE_MENU_AT.contains(&page) {
+            if page.is_separated() {
                 ui.separator();
             }
 
-            if self.current_page == page {
+            if self.navigator.current_page() == Some(page) {
                 ui.add(egui::Button::new(page.title()))
                     .highlight()
                     .on_hover_text(format!("{} (current page)", page.description()));
@@ -188,14 +237,14 @@ impl App {
                 // If the button is clicked, change the current page
                 if button.clicked() {
                     // Change URL to the new page in the same tab
-                    crate::utils::egui::open_url_on_page(ui.ctx(), page, true);
+                    crate::navigation::open_route(ui.ctx(), page, true);
 
                     // Close menu if it is open
                     ui.close_menu();
                 } else {
                     // Open URL in a new page if the middle mouse button is clicked
                     if button.middle_clicked() {
-                        crate::utils::egui::open_url_on_page(ui.ctx(), page, false);
+                        crate::navigation::open_route(ui.ctx(), page, false);
                     }
                 }
             }
@@ -203,7 +252,7 @@ impl App {
     }
 
     pub fn dark_mode_toggle_button(&mut self, ui: &mut egui::Ui) {
-        let (icon, tooltip, target_visuals) = match self.theme {
+        let (icon, tooltip, target_visuals) = match self.state.theme {
             egui::Theme::Dark => (
                 "\u{e51c}",
                 "Switch to light mode",
@@ -222,7 +271,7 @@ impl App {
             .clicked()
         {
             ui.ctx().set_visuals(target_visuals.to_owned());
-            self.theme = match self.theme {
+            self.state.theme = match self.state.theme {
                 egui::Theme::Dark => egui::Theme::Light,
                 egui::Theme::Light => egui::Theme::Dark,
             };
@@ -252,13 +301,54 @@ impl App {
                                     "Current page: {:?}\n\
                                      Screen size:  {:?}\n\
                                     ",
-                                    self.current_page,
+                                    self.navigator.current_page(),
                                     ui.ctx().screen_rect().size(),
                                 ))
                                 .font(egui::FontId::monospace(
                                     ui.style().text_styles[&egui::TextStyle::Button].size,
                                 )),
                             );
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "Page initialisation:\n{}",
+                                    self.pages
+                                        .init_times()
+                                        .iter()
+                                        .map(|(page, duration)| format!(
+                                            "  {:<10} {:>8.2} ms",
+                                            page.slug(),
+                                            duration.as_secs_f64() * 1e3
+                                        ))
+                                        .join("\n")
+                                ))
+                                .font(egui::FontId::monospace(
+                                    ui.style().text_styles[&egui::TextStyle::Small].size,
+                                )),
+                            );
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "Short links:\n{}",
+                                    crate::navigation::redirects()
+                                        .iter()
+                                        .map(|redirect| format!(
+                                            "  /{} -> {}{}",
+                                            redirect.slug,
+                                            redirect.target,
+                                            match redirect.expires {
+                                                Some(expires)
+                                                    if redirect
+                                                        .is_expired(crate::navigation::today()) =>
+                                                    format!(" (expired {expires})"),
+                                                Some(expires) => format!(" (until {expires})"),
+                                                None => String::new(),
+                                            }
+                                        ))
+                                        .join("\n")
+                                ))
+                                .font(egui::FontId::monospace(
+                                    ui.style().text_styles[&egui::TextStyle::Small].size,
+                                )),
+                            );
                         })
                 });
             }
@@ -0,0 +1,412 @@
+//! Citations of publications formatted as plain text in common styles.
+
+use itertools::Itertools;
+
+use super::model::{Person, Publication};
+
+/// Style of a formatted citation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CitationStyle {
+    Apa,
+    Ieee,
+    Chicago,
+    Mla,
+}
+
+impl CitationStyle {
+    pub const ALL: &[Self] = &[Self::Apa, Self::Ieee, Self::Chicago, Self::Mla];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Apa => "APA",
+            Self::Ieee => "IEEE",
+            Self::Chicago => "Chicago",
+            Self::Mla => "MLA",
+        }
+    }
+}
+
+impl Publication {
+    /// Citation of the publication in the given style.
+    pub fn cite(&self, style: CitationStyle) -> String {
+        match style {
+            CitationStyle::Apa => self.cite_apa(),
+            CitationStyle::Ieee => self.cite_ieee(),
+            CitationStyle::Chicago => self.cite_chicago(),
+            CitationStyle::Mla => self.cite_mla(),
+        }
+    }
+
+    /// E.g. `Orsula, A., & Bøgh, S. (2022). Title. In IROS 2022 (pp. 4112–4119). IEEE.`
+    fn cite_apa(&self) -> String {
+        let authors = self
+            .authors
+            .iter()
+            .map(|author| join([author.name.as_ref(), &initials(author)], ", "))
+            .collect_vec();
+        let authors = match authors.as_slice() {
+            [] => String::new(),
+            [author] => author.clone(),
+            [authors @ .., last] => format!("{}, & {last}", authors.join(", ")),
+        };
+        let year = self
+            .date
+            .year
+            .map_or_else(|| "in press".to_owned(), |year| year.to_string());
+        let details = &self.details;
+        let pages = self.pages();
+
+        let source = match self.entry_type.as_ref() {
+            "article" => join(
+                [
+                    self.venue.as_deref().unwrap_or_default(),
+                    &join(
+                        [
+                            details.volume.as_deref().unwrap_or_default(),
+                            &details
+                                .number
+                                .as_ref()
+                                .map(|number| format!("({number})"))
+                                .unwrap_or_default(),
+                        ],
+                        "",
+                    ),
+                    pages.as_deref().unwrap_or_default(),
+                ],
+                ", ",
+            ),
+            "mastersthesis" | "phdthesis" | "thesis" => format!(
+                "[{}]",
+                join(
+                    [
+                        self.venue.as_deref().unwrap_or("Thesis"),
+                        details.publisher.as_deref().unwrap_or_default(),
+                    ],
+                    ", "
+                )
+            ),
+            _ => join(
+                [
+                    &self
+                        .venue
+                        .as_ref()
+                        .map(|venue| {
+                            let pages = pages
+                                .as_ref()
+                                .map(|pages| format!(" (pp. {pages})"))
+                                .unwrap_or_default();
+                            format!("In {venue}{pages}")
+                        })
+                        .unwrap_or_default(),
+                    details.publisher.as_deref().unwrap_or_default(),
+                ],
+                ". ",
+            ),
+        };
+
+        self.with_link(sentences([
+            authors,
+            format!("({year})"),
+            self.title.to_string(),
+            source,
+        ]))
+    }
+
+    /// E.g. `A. Orsula and S. Bøgh, "Title," in IROS 2022, IEEE, 2022, pp. 4112–4119.`
+    fn cite_ieee(&self) -> String {
+        let authors = self
+            .authors
+            .iter()
+            .map(|author| join([&initials(author), author.name.as_ref()], " "))
+            .collect_vec();
+        let authors = match authors.as_slice() {
+            [] => String::new(),
+            [author] => author.clone(),
+            [first, second] => format!("{first} and {second}"),
+            [authors @ .., last] => format!("{}, and {last}", authors.join(", ")),
+        };
+        let details = &self.details;
+        let venue = self.venue.as_deref().unwrap_or_default();
+
+        let source = join(
+            [
+                &match self.entry_type.as_ref() {
+                    "article" => venue.to_owned(),
+                    "mastersthesis" | "phdthesis" | "thesis" => venue.to_owned(),
+                    _ if venue.is_empty() => String::new(),
+                    _ => format!("in {venue}"),
+                },
+                details.publisher.as_deref().unwrap_or_default(),
+                &details
+                    .volume
+                    .as_ref()
+                    .map(|volume| format!("vol. {volume}"))
+                    .unwrap_or_default(),
+                &details
+                    .number
+                    .as_ref()
+                    .map(|number| format!("no. {number}"))
+                    .unwrap_or_default(),
+                &self
+                    .date
+                    .year
+                    .map(|year| year.to_string())
+                    .unwrap_or_default(),
+                &self
+                    .pages()
+                    .map(|pages| format!("pp. {pages}"))
+                    .unwrap_or_default(),
+            ],
+            ", ",
+        );
+
+        let title = match source.is_empty() {
+            true => quoted(&self.title, '.'),
+            false => format!("{} {source}", quoted(&self.title, ',')),
+        };
+        self.with_link(join([authors, title], ", ") + ".")
+    }
+
+    /// E.g. `Orsula, Andrej, and Simon Bøgh. "Title." In IROS 2022, 4112–4119. IEEE, 2022.`
+    fn cite_chicago(&self) -> String {
+        let authors = self
+            .authors
+            .iter()
+            .enumerate()
+            .map(|(i, author)| {
+                if i == 0 {
+                    join([author.name.as_ref(), author.given_name.as_ref()], ", ")
+                } else {
+                    author.full_name()
+                }
+            })
+            .collect_vec();
+        let authors = match authors.as_slice() {
+            [] => String::new(),
+            [author] => author.clone(),
+            [first, second] => format!("{first}, and {second}"),
+            [authors @ .., last] => format!("{}, and {last}", authors.join(", ")),
+        };
+        let details = &self.details;
+        let year = self
+            .date
+            .year
+            .map_or_else(|| "Forthcoming".to_owned(), |year| year.to_string());
+        let venue = self.venue.as_deref().unwrap_or_default();
+
+        let source = match self.entry_type.as_ref() {
+            "article" => {
+                let volume = join(
+                    [
+                        details.volume.as_deref().unwrap_or_default(),
+                        &details
+                            .number
+                            .as_ref()
+                            .map(|number| format!("no. {number}"))
+                            .unwrap_or_default(),
+                    ],
+                    ", ",
+                );
+                let pages = self
+                    .pages()
+                    .map(|pages| format!(": {pages}"))
+                    .unwrap_or_default();
+                format!("{} ({year}){pages}", join([venue, &volume], " "))
+            }
+            "mastersthesis" | "phdthesis" | "thesis" => join(
+                [
+                    venue,
+                    details.publisher.as_deref().unwrap_or_default(),
+                    &year,
+                ],
+                ", ",
+            ),
+            _ => sentences([
+                join(
+                    [
+                        &(if venue.is_empty() {
+                            String::new()
+                        } else {
+                            format!("In {venue}")
+                        }),
+                        &self.pages().unwrap_or_default(),
+                    ],
+                    ", ",
+                ),
+                join(
+                    [details.publisher.as_deref().unwrap_or_default(), &year],
+                    ", ",
+                ),
+            ]),
+        };
+
+        self.with_link(sentences([authors, quoted(&self.title, '.'), source]))
+    }
+
+    /// E.g. `Orsula, Andrej, et al. "Title." IROS 2022, IEEE, 2022, pp. 4112–4119.`
+    fn cite_mla(&self) -> String {
+        let authors = match self.authors.as_ref() {
+            [] => String::new(),
+            [author] => join([author.name.as_ref(), author.given_name.as_ref()], ", "),
+            [first, second] => format!(
+                "{}, and {}",
+                join([first.name.as_ref(), first.given_name.as_ref()], ", "),
+                second.full_name()
+            ),
+            [first, ..] => format!(
+                "{}, et al",
+                join([first.name.as_ref(), first.given_name.as_ref()], ", ")
+            ),
+        };
+        let details = &self.details;
+
+        let source = join(
+            [
+                self.venue.as_deref().unwrap_or_default(),
+                &details
+                    .volume
+                    .as_ref()
+                    .map(|volume| format!("vol. {volume}"))
+                    .unwrap_or_default(),
+                &details
+                    .number
+                    .as_ref()
+                    .map(|number| format!("no. {number}"))
+                    .unwrap_or_default(),
+                details.publisher.as_deref().unwrap_or_default(),
+                &self
+                    .date
+                    .year
+                    .map(|year| year.to_string())
+                    .unwrap_or_default(),
+                &self
+                    .pages()
+                    .map(|pages| format!("pp. {pages}"))
+                    .unwrap_or_default(),
+            ],
+            ", ",
+        );
+
+        self.with_link(sentences([authors, quoted(&self.title, '.'), source]))
+    }
+
+    /// Page range with an en dash, e.g. `4112–4119`.
+    fn pages(&self) -> Option<String> {
+        self.details
+            .pages
+            .as_ref()
+            .map(|pages| pages.replace("--", "\u{2013}"))
+    }
+
+    /// Citation followed by the DOI or permalink of the publication, if any.
+    fn with_link(&self, citation: String) -> String {
+        let link = self
+            .details
+            .doi
+            .as_ref()
+            .map(|doi| format!("https://doi.org/{doi}"))
+            .or_else(|| self.links.publication_url.as_ref().map(ToString::to_string));
+        match link {
+            Some(link) => format!("{citation} {link}"),
+            None => citation,
+        }
+    }
+}
+
+/// Initials of the given name of a person, e.g. `J.-L. M.` for `Jean-Luc Marie`.
+fn initials(person: &Person) -> String {
+    person
+        .given_name
+        .split_whitespace()
+        .map(|name| {
+            name.split('-')
+                .filter_map(|part| part.chars().next())
+                .map(|initial| format!("{initial}."))
+                .join("-")
+        })
+        .join(" ")
+}
+
+/// Title in quotes that ends with the punctuation, unless it already ends with its own, e.g.
+/// `“Title,”` but `“Title?”`.
+fn quoted(title: &str, punctuation: char) -> String {
+    if title.ends_with(['.', '?', '!']) {
+        format!("\u{201c}{title}\u{201d}")
+    } else {
+        format!("\u{201c}{title}{punctuation}\u{201d}")
+    }
+}
+
+/// Join the non-empty parts with the separator.
+fn join<S: AsRef<str>>(parts: impl IntoIterator<Item = S>, separator: &str) -> String {
+    parts
+        .into_iter()
+        .filter(|part| !part.as_ref().is_empty())
+        .map(|part| part.as_ref().to_owned())
+        .join(separator)
+}
+
+/// Join the non-empty parts as sentences that each end with a period.
+fn sentences(parts: impl IntoIterator<Item = String>) -> String {
+    parts
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            if part.ends_with(['.', '?', '!', '\u{201d}']) {
+                part
+            } else {
+                format!("{part}.")
+            }
+        })
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cite_publications() {
+        let [iros, _, article] = crate::content::test_publications().try_into().unwrap();
+
+        assert_eq!(
+            iros.cite(CitationStyle::Apa),
+            "Orsula, A., Bøgh, S., & Olivares-Mendez, M. (2022). Learning to Grasp on the Moon. In IROS 2022 (pp. 4112–4119). IEEE. https://doi.org/10.1109/IROS47612.2022.9981661"
+        );
+        assert_eq!(
+            iros.cite(CitationStyle::Ieee),
+            "A. Orsula, S. Bøgh, and M. Olivares-Mendez, \u{201c}Learning to Grasp on the Moon,\u{201d} in IROS 2022, IEEE, 2022, pp. 4112–4119. https://doi.org/10.1109/IROS47612.2022.9981661"
+        );
+        assert_eq!(
+            iros.cite(CitationStyle::Mla),
+            "Orsula, Andrej, et al. \u{201c}Learning to Grasp on the Moon.\u{201d} IROS 2022, IEEE, 2022, pp. 4112–4119. https://doi.org/10.1109/IROS47612.2022.9981661"
+        );
+
+        assert_eq!(
+            article.cite(CitationStyle::Apa),
+            "Orsula, A., & Bøgh, S. (in press). Title. IEEE Access, 12(3)."
+        );
+        assert_eq!(
+            article.cite(CitationStyle::Chicago),
+            "Orsula, Andrej, and Simon Bøgh. \u{201c}Title.\u{201d} IEEE Access 12, no. 3 (Forthcoming)."
+        );
+
+        // Titles that end with their own punctuation are not followed by another one
+        let question = Publication {
+            title: "Can Robots Grasp on the Moon?".into(),
+            ..iros.clone()
+        };
+        assert_eq!(
+            question.cite(CitationStyle::Ieee),
+            "A. Orsula, S. Bøgh, and M. Olivares-Mendez, \u{201c}Can Robots Grasp on the Moon?\u{201d} in IROS 2022, IEEE, 2022, pp. 4112–4119. https://doi.org/10.1109/IROS47612.2022.9981661"
+        );
+        assert_eq!(
+            question.cite(CitationStyle::Chicago),
+            "Orsula, Andrej, Simon Bøgh, and Miguel Olivares-Mendez. \u{201c}Can Robots Grasp on the Moon?\u{201d} In IROS 2022, 4112–4119. IEEE, 2022. https://doi.org/10.1109/IROS47612.2022.9981661"
+        );
+        assert_eq!(
+            question.cite(CitationStyle::Mla),
+            "Orsula, Andrej, et al. \u{201c}Can Robots Grasp on the Moon?\u{201d} IROS 2022, IEEE, 2022, pp. 4112–4119. https://doi.org/10.1109/IROS47612.2022.9981661"
+        );
+    }
+}
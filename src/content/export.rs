@@ -0,0 +1,308 @@
+//! Export of publications to the file formats of reference managers.
+//!
+//! Only the standard fields are exported, so the custom link fields of the bibliography are
+//! stripped just like in the copied BibTeX entries.
+
+use itertools::Itertools;
+
+use super::{model::Publication, query::PublicationKind};
+
+/// File format of exported publications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Bibtex,
+    Ris,
+    CslJson,
+    EndnoteXml,
+}
+
+impl ExportFormat {
+    pub const ALL: &[Self] = &[Self::Bibtex, Self::Ris, Self::CslJson, Self::EndnoteXml];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Bibtex => "BibTeX",
+            Self::Ris => "RIS",
+            Self::CslJson => "CSL-JSON",
+            Self::EndnoteXml => "EndNote XML",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Bibtex => "bib",
+            Self::Ris => "ris",
+            Self::CslJson => "json",
+            Self::EndnoteXml => "xml",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Bibtex => "application/x-bibtex",
+            Self::Ris => "application/x-research-info-systems",
+            Self::CslJson => "application/vnd.citationstyles.csl+json",
+            Self::EndnoteXml => "application/xml",
+        }
+    }
+
+    /// Contents of a file with the given publications.
+    pub fn export<'a>(self, publications: impl IntoIterator<Item = &'a Publication>) -> String {
+        let publications = publications.into_iter();
+        match self {
+            Self::Bibtex => publications
+                .map(|publication| format!("{}\n", publication.bibtex.trim_end()))
+                .join("\n"),
+            Self::Ris => publications.map(ris).join("\n"),
+            Self::CslJson => {
+                let items = publications.map(csl_json).collect_vec();
+                let json = serde_json::to_string_pretty(&items).expect("Items are serializable");
+                format!("{json}\n")
+            }
+            Self::EndnoteXml => format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<xml>\n<records>\n{}</records>\n</xml>\n",
+                publications.map(endnote_xml).join("")
+            ),
+        }
+    }
+}
+
+impl Publication {
+    /// First and last page of the page range, e.g. `4112` and `4119` for `4112--4119`.
+    fn page_range(&self) -> Option<(&str, Option<&str>)> {
+        let pages = self.details.pages.as_deref()?.trim();
+        Some(match pages.split_once(['-', '\u{2013}']) {
+            Some((first, last)) => (
+                first.trim(),
+                Some(last.trim_start_matches(['-', '\u{2013}']).trim()),
+            ),
+            None => (pages, None),
+        })
+    }
+}
+
+/// Entry of a RIS file, which lists one `TAG  - value` pair per line.
+fn ris(publication: &Publication) -> String {
+    let entry_type = match publication.kind() {
+        PublicationKind::Journal => "JOUR",
+        PublicationKind::Conference => "CPAPER",
+        PublicationKind::Thesis => "THES",
+        PublicationKind::Preprint => "UNPB",
+        PublicationKind::Other => "GEN",
+    };
+    let details = &publication.details;
+    let date = &publication.date;
+    let (start_page, end_page) = publication.page_range().unzip();
+
+    let mut tags = vec![("TY", entry_type.to_owned())];
+    tags.extend(
+        publication
+            .authors
+            .iter()
+            .map(|author| ("AU", format!("{}, {}", author.name, author.given_name))),
+    );
+    tags.extend(
+        [
+            ("TI", Some(publication.title.to_string())),
+            ("T2", publication.venue.as_ref().map(ToString::to_string)),
+            ("PY", date.year.map(|year| year.to_string())),
+            (
+                "DA",
+                date.year.map(|year| {
+                    let part = |part: Option<u8>| part.map(|part| format!("{part:02}"));
+                    format!(
+                        "{year}/{}/{}/",
+                        part(date.month).unwrap_or_default(),
+                        part(date.day).unwrap_or_default()
+                    )
+                }),
+            ),
+            ("VL", details.volume.as_ref().map(ToString::to_string)),
+            ("IS", details.number.as_ref().map(ToString::to_string)),
+            ("SP", start_page.map(str::to_owned)),
+            ("EP", end_page.flatten().map(str::to_owned)),
+            ("PB", details.publisher.as_ref().map(ToString::to_string)),
+            ("DO", details.doi.as_ref().map(ToString::to_string)),
+            ("ID", Some(publication.key.to_string())),
+        ]
+        .into_iter()
+        .filter_map(|(tag, value)| Some((tag, value?))),
+    );
+    tags.push(("ER", String::new()));
+
+    tags.into_iter()
+        .map(|(tag, value)| format!("{tag}  - {value}").trim_end().to_owned() + "\n")
+        .collect()
+}
+
+/// Item of a CSL-JSON file.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct CslItem<'a> {
+    id: &'a str,
+    #[serde(rename = "type")]
+    item_type: &'static str,
+    title: &'a str,
+    author: Vec<CslName<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issued: Option<CslDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    container_title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    publisher: Option<&'a str>,
+    #[serde(rename = "DOI", skip_serializing_if = "Option::is_none")]
+    doi: Option<&'a str>,
+}
+
+#[derive(serde::Serialize)]
+struct CslName<'a> {
+    family: &'a str,
+    given: &'a str,
+}
+
+#[derive(serde::Serialize)]
+struct CslDate {
+    #[serde(rename = "date-parts")]
+    date_parts: [Vec<i32>; 1],
+}
+
+fn csl_json(publication: &Publication) -> CslItem<'_> {
+    let details = &publication.details;
+    let date = &publication.date;
+    CslItem {
+        id: &publication.key,
+        item_type: match publication.kind() {
+            PublicationKind::Journal => "article-journal",
+            PublicationKind::Conference => "paper-conference",
+            PublicationKind::Thesis => "thesis",
+            PublicationKind::Preprint => "article",
+            PublicationKind::Other => "document",
+        },
+        title: &publication.title,
+        author: publication
+            .authors
+            .iter()
+            .map(|author| CslName {
+                family: &author.name,
+                given: &author.given_name,
+            })
+            .collect(),
+        issued: date.year.map(|year| CslDate {
+            date_parts: [std::iter::once(year)
+                .chain(date.month.map(i32::from))
+                .chain(date.month.and(date.day).map(i32::from))
+                .collect()],
+        }),
+        container_title: publication.venue.as_deref(),
+        volume: details.volume.as_deref(),
+        issue: details.number.as_deref(),
+        page: publication
+            .page_range()
+            .map(|(first, last)| join_pages(first, last)),
+        publisher: details.publisher.as_deref(),
+        doi: details.doi.as_deref(),
+    }
+}
+
+/// Record of an EndNote XML file.
+fn endnote_xml(publication: &Publication) -> String {
+    let (ref_type, ref_type_name) = match publication.kind() {
+        PublicationKind::Journal => (17, "Journal Article"),
+        PublicationKind::Conference => (47, "Conference Paper"),
+        PublicationKind::Thesis => (32, "Thesis"),
+        PublicationKind::Preprint | PublicationKind::Other => (13, "Generic"),
+    };
+    let details = &publication.details;
+    let element = |name: &str, value: Option<&str>| {
+        value
+            .map(|value| format!("<{name}>{}</{name}>", escape_xml(value)))
+            .unwrap_or_default()
+    };
+
+    let authors = publication
+        .authors
+        .iter()
+        .map(|author| {
+            element(
+                "author",
+                Some(&format!("{}, {}", author.name, author.given_name)),
+            )
+        })
+        .join("");
+    let pages = publication
+        .page_range()
+        .map(|(first, last)| join_pages(first, last));
+    let year = publication.date.year.map(|year| year.to_string());
+
+    format!(
+        "<record>\
+         <ref-type name=\"{ref_type_name}\">{ref_type}</ref-type>\
+         <contributors><authors>{authors}</authors></contributors>\
+         <titles>{}{}</titles>\
+         {}{}{}{}{}{}{}\
+         </record>\n",
+        element("title", Some(&publication.title)),
+        element("secondary-title", publication.venue.as_deref()),
+        element("pages", pages.as_deref()),
+        element("volume", details.volume.as_deref()),
+        element("number", details.number.as_deref()),
+        year.map(|year| format!("<dates><year>{year}</year></dates>"))
+            .unwrap_or_default(),
+        element("publisher", details.publisher.as_deref()),
+        element("electronic-resource-num", details.doi.as_deref()),
+        element("label", Some(&publication.key)),
+    )
+}
+
+/// Page range with a hyphen, which reference managers expect instead of an en dash.
+fn join_pages(first: &str, last: Option<&str>) -> String {
+    match last {
+        Some(last) => format!("{first}-{last}"),
+        None => first.to_owned(),
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_publications() {
+        let publications = crate::content::test_publications();
+
+        let bibtex = ExportFormat::Bibtex.export(&publications);
+        assert_eq!(bibtex.matches('@').count(), 3);
+        assert!(!bibtex.contains("repository"));
+
+        let ris = ExportFormat::Ris.export(&publications[..1]);
+        assert_eq!(
+            ris,
+            "TY  - CPAPER\nAU  - Orsula, Andrej\nAU  - Bøgh, Simon\nAU  - Olivares-Mendez, Miguel\nTI  - Learning to Grasp on the Moon\nT2  - IROS 2022\nPY  - 2022\nDA  - 2022///\nSP  - 4112\nEP  - 4119\nPB  - IEEE\nDO  - 10.1109/IROS47612.2022.9981661\nID  - orsula2022learning\nER  -\n"
+        );
+
+        let csl_json = ExportFormat::CslJson.export(&publications);
+        assert!(csl_json.contains("\"type\": \"paper-conference\""));
+        assert!(csl_json.contains("\"page\": \"4112-4119\""));
+        assert!(csl_json.contains("\"DOI\": \"10.1109/ACCESS.2024.0000000\""));
+        assert!(!csl_json.contains("github"));
+
+        let endnote_xml = ExportFormat::EndnoteXml.export(&publications[1..2]);
+        assert!(endnote_xml.contains("<ref-type name=\"Journal Article\">17</ref-type>"));
+        assert!(endnote_xml.contains("<title>Sim &amp; Real</title>"));
+        assert!(endnote_xml.contains("<dates><year>2024</year></dates>"));
+    }
+}
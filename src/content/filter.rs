@@ -0,0 +1,247 @@
+//! Filtering of the publications by a search text, years, kinds and co-authors.
+
+use std::{collections::BTreeSet, ops::Range};
+
+use super::{model::Publication, query::PublicationKind};
+
+/// Filter of the listed publications, which can be kept in a URL via its parameters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublicationFilter {
+    /// Search text whose words must all appear in the title, authors, venue or keywords
+    pub text: String,
+    /// First listed year, or `None` for no lower bound
+    pub from_year: Option<i32>,
+    /// Last listed year, or `None` for no upper bound (the publications without a year are only
+    /// listed if neither bound is set)
+    pub to_year: Option<i32>,
+    /// Kinds of the listed publications, or all kinds if empty
+    pub kinds: BTreeSet<PublicationKind>,
+    /// Full name of an author that must be among the authors, e.g. `Simon Bøgh`
+    pub coauthor: Option<String>,
+}
+
+impl PublicationFilter {
+    /// Kinds that can be selected in the filter.
+    pub const KINDS: &[PublicationKind] = &[
+        PublicationKind::Journal,
+        PublicationKind::Conference,
+        PublicationKind::Thesis,
+        PublicationKind::Preprint,
+    ];
+
+    pub fn is_active(&self) -> bool {
+        *self != Self::default()
+    }
+
+    /// Words of the search text in lowercase.
+    fn terms(&self) -> impl Iterator<Item = String> + '_ {
+        self.text.split_whitespace().map(str::to_lowercase)
+    }
+
+    pub fn matches(&self, publication: &Publication) -> bool {
+        let searchable = std::iter::once(publication.title.to_string())
+            .chain(publication.authors.iter().map(|author| author.full_name()))
+            .chain(publication.venue.iter().map(ToString::to_string))
+            .chain(publication.keywords.iter().map(ToString::to_string))
+            .collect::<Vec<_>>()
+            .join("\n")
+            .to_lowercase();
+
+        self.terms().all(|term| searchable.contains(&term))
+            && (self.from_year.is_none() && self.to_year.is_none()
+                || publication.date.year.is_some_and(|year| {
+                    self.from_year.is_none_or(|from| from <= year)
+                        && self.to_year.is_none_or(|to| year <= to)
+                }))
+            && (self.kinds.is_empty() || self.kinds.contains(&publication.kind()))
+            && self.coauthor.as_ref().is_none_or(|coauthor| {
+                publication
+                    .authors
+                    .iter()
+                    .any(|author| author.full_name() == *coauthor)
+            })
+    }
+
+    /// Byte ranges of the text that match the words of the search text, in order and without
+    /// overlaps.
+    pub fn highlights(&self, text: &str) -> Vec<Range<usize>> {
+        let mut ranges = self
+            .terms()
+            .flat_map(|term| find_ignore_case(text, &term))
+            .collect::<Vec<_>>();
+        ranges.sort_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+
+    /// Parameters of the filter that differ from the default, e.g. `("type", "journal")`.
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        let year = |year: Option<i32>| year.map(|year| year.to_string()).unwrap_or_default();
+        vec![
+            ("q", self.text.trim().to_owned()),
+            ("from", year(self.from_year)),
+            ("to", year(self.to_year)),
+            (
+                "type",
+                self.kinds
+                    .iter()
+                    .map(|kind| kind.slug())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            ("author", self.coauthor.clone().unwrap_or_default()),
+        ]
+    }
+
+    /// Filter with the given parameters, ignoring the unknown and malformed ones.
+    pub fn from_params(params: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut filter = Self::default();
+        for (name, value) in params {
+            match name.as_str() {
+                "q" => filter.text = value,
+                "from" => filter.from_year = value.parse().ok(),
+                "to" => filter.to_year = value.parse().ok(),
+                "type" => {
+                    filter.kinds = value
+                        .split(',')
+                        .filter_map(|slug| {
+                            Self::KINDS.iter().copied().find(|kind| kind.slug() == slug)
+                        })
+                        .collect();
+                }
+                "author" if !value.is_empty() => filter.coauthor = Some(value),
+                _ => {}
+            }
+        }
+        filter
+    }
+}
+
+/// Byte ranges of the occurrences of the lowercase needle in the text, ignoring case.
+fn find_ignore_case(text: &str, needle: &str) -> Vec<Range<usize>> {
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    text.char_indices()
+        .filter_map(|(start, _)| {
+            let mut haystack = text[start..].chars();
+            let mut end = start;
+            for expected in needle.chars() {
+                let c = haystack.next()?;
+                if !c.to_lowercase().eq(std::iter::once(expected)) {
+                    return None;
+                }
+                end += c.len_utf8();
+            }
+            Some(start..end)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_publications() {
+        let publications = crate::content::test_publications();
+        let matching = |filter: &PublicationFilter| {
+            publications
+                .iter()
+                .filter(|publication| filter.matches(publication))
+                .map(|publication| publication.key.as_ref())
+                .collect::<Vec<_>>()
+        };
+
+        let all = PublicationFilter::default();
+        assert!(!all.is_active());
+        assert_eq!(matching(&all).len(), 3);
+
+        let search = |text: &str| PublicationFilter {
+            text: text.to_owned(),
+            ..PublicationFilter::default()
+        };
+        assert_eq!(matching(&search("moon GRASP")), ["orsula2022learning"]);
+        assert_eq!(matching(&search("robotics bøgh")), ["orsula2022learning"]);
+        assert_eq!(matching(&search("real")), ["orsula2024access"]);
+        assert_eq!(
+            matching(&search("access")),
+            ["orsula2024access", "orsula2024forthcoming"]
+        );
+        assert!(matching(&search("mars")).is_empty());
+
+        let filter = PublicationFilter {
+            from_year: Some(2023),
+            to_year: Some(2024),
+            ..PublicationFilter::default()
+        };
+        assert_eq!(matching(&filter), ["orsula2024access"]);
+        let filter = PublicationFilter {
+            to_year: Some(2023),
+            ..PublicationFilter::default()
+        };
+        assert_eq!(matching(&filter), ["orsula2022learning"]);
+        let filter = PublicationFilter {
+            kinds: BTreeSet::from([PublicationKind::Conference]),
+            ..PublicationFilter::default()
+        };
+        assert_eq!(matching(&filter), ["orsula2022learning"]);
+        let filter = PublicationFilter {
+            coauthor: Some("Simon Bøgh".to_owned()),
+            ..PublicationFilter::default()
+        };
+        assert_eq!(
+            matching(&filter),
+            ["orsula2022learning", "orsula2024forthcoming"]
+        );
+
+        assert_eq!(
+            search("grasp the moon g").highlights("Learning to Grasp on the Moon"),
+            [7..8, 12..17, 21..24, 25..29]
+        );
+    }
+
+    #[test]
+    fn filter_params() {
+        let filter = PublicationFilter {
+            text: "moon".to_owned(),
+            from_year: None,
+            to_year: Some(2023),
+            kinds: BTreeSet::from([PublicationKind::Journal, PublicationKind::Preprint]),
+            coauthor: Some("Simon Bøgh".to_owned()),
+        };
+        let params = filter.params();
+        assert_eq!(
+            params,
+            [
+                ("q", "moon".to_owned()),
+                ("from", String::new()),
+                ("to", "2023".to_owned()),
+                ("type", "journal,preprint".to_owned()),
+                ("author", "Simon Bøgh".to_owned()),
+            ]
+        );
+        assert_eq!(
+            PublicationFilter::from_params(
+                params
+                    .into_iter()
+                    .map(|(name, value)| (name.to_owned(), value))
+            ),
+            filter
+        );
+        assert_eq!(
+            PublicationFilter::from_params([
+                ("from".to_owned(), "soon".to_owned()),
+                ("type".to_owned(), "poem".to_owned()),
+            ]),
+            PublicationFilter::default()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Markdown documents with the outline of their headings.
+
+use super::model::Text;
+
+/// Markdown document with its headings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Markdown {
+    source: Text,
+    headings: Vec<Heading>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heading {
+    pub level: usize,
+    pub title: String,
+}
+
+impl Markdown {
+    pub fn new(source: impl Into<Text>) -> Self {
+        let source = source.into();
+        Self {
+            headings: parse_headings(&source),
+            source,
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Headings in the order of the document.
+    pub fn headings(&self) -> &[Heading] {
+        &self.headings
+    }
+}
+
+/// Collect the headings of the source along with their plain-text titles.
+fn parse_headings(source: &str) -> Vec<Heading> {
+    use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+
+    let mut headings = Vec::new();
+    let mut title = None;
+    for event in Parser::new(source) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => title = Some(String::new()),
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(title) = &mut title {
+                    title.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(level)) => headings.push(Heading {
+                level: level as usize,
+                title: title.take().unwrap_or_default(),
+            }),
+            _ => {}
+        }
+    }
+    headings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_headings() {
+        let document = Markdown::new(
+            "Intro\n\n# First `code`\n\nText\n\n## Nested\n\n```\n# Not a heading\n```\n",
+        );
+        assert_eq!(
+            document
+                .headings()
+                .iter()
+                .map(|heading| (heading.level, heading.title.as_str()))
+                .collect::<Vec<_>>(),
+            [(1, "First code"), (2, "Nested")]
+        );
+        assert!(Markdown::new("Text only").headings().is_empty());
+    }
+}
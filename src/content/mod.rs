@@ -0,0 +1,118 @@
+//! Content of the site that is independent of its presentation.
+//!
+//! The module does not depend on egui, so the same data model, parsing, sorting and querying
+//! can be reused outside of the app and tested without a context. Presentation lives in the
+//! page modules.
+//!
+//! The updates and the bibliography are parsed by the build script, which shares the [`model`]
+//! and the parsers with this module. Malformed content therefore fails the build, and the app
+//! only embeds the parsed data. The same parsers are used for the content that is fetched at
+//! runtime with the `remote-content` feature or hot-reloaded in debug builds.
+
+#[cfg(feature = "bibliography")]
+pub use cite::CitationStyle;
+#[cfg(feature = "bibliography")]
+pub use export::ExportFormat;
+#[cfg(feature = "bibliography")]
+pub use filter::PublicationFilter;
+pub use markdown::Markdown;
+pub use model::Update;
+#[cfg(feature = "bibliography")]
+pub use model::{Person, Publication, PublicationDate, PublicationDetails, PublicationLinks};
+#[cfg(all(
+    feature = "bibliography",
+    not(any(hot_reload, feature = "remote-content"))
+))]
+pub use query::embedded_thumbnail;
+#[cfg(feature = "bibliography")]
+pub use query::{youtube_thumbnail, SortMode};
+
+#[cfg(feature = "bibliography")]
+mod cite;
+#[cfg(feature = "bibliography")]
+mod export;
+#[cfg(feature = "bibliography")]
+mod filter;
+mod markdown;
+// The build script compiles both modules without the features of the crate, so the parts that
+// are specific to the bibliography cannot be removed from them with `cfg`
+#[cfg_attr(not(feature = "bibliography"), allow(dead_code))]
+mod model;
+#[cfg(any(test, hot_reload, feature = "remote-content"))]
+#[cfg_attr(not(feature = "bibliography"), allow(dead_code))]
+pub mod parse;
+#[cfg(feature = "bibliography")]
+mod query;
+
+use std::borrow::Cow;
+
+include!(concat!(env!("OUT_DIR"), "/content.rs"));
+
+/// Bibliography that the tests of the content share, with a conference paper, a journal article
+/// and a forthcoming journal article.
+#[cfg(all(test, feature = "bibliography"))]
+const TEST_BIBLIOGRAPHY: &str = indoc::indoc! {r"
+    @inproceedings{orsula2022learning,
+      title        = {{Learning to Grasp on the Moon}},
+      author       = {Orsula, Andrej and B{\o}gh, Simon and Olivares-Mendez, Miguel},
+      booktitle    = {IROS 2022},
+      year         = {2022},
+      organization = {IEEE},
+      pages        = {4112--4119},
+      doi          = {10.1109/IROS47612.2022.9981661},
+      keywords     = {space robotics},
+      repository   = {https://github.com/AndrejOrsula/drl_grasping},
+    }
+
+    @article{orsula2024access,
+      title   = {Sim & Real},
+      author  = {Orsula, Andrej},
+      journal = {IEEE Access},
+      date    = {2024-03},
+      doi     = {10.1109/ACCESS.2024.0000000},
+    }
+
+    @article{orsula2024forthcoming,
+      title   = {Title},
+      author  = {Orsula, Andrej and B{\o}gh, Simon},
+      journal = {IEEE Access},
+      volume  = {12},
+      number  = {3},
+    }
+"};
+
+/// Publications of the [`TEST_BIBLIOGRAPHY`].
+#[cfg(all(test, feature = "bibliography"))]
+fn test_publications() -> Vec<Publication> {
+    parse_publications(TEST_BIBLIOGRAPHY)
+}
+
+/// Parse a bibliography in which every entry is valid.
+#[cfg(all(test, feature = "bibliography"))]
+fn parse_publications(src: &str) -> Vec<Publication> {
+    parse::publications(src)
+        .unwrap()
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_content() {
+        assert_eq!(
+            parse::updates(crate::macros::include_content_str!("updates.yaml")).unwrap(),
+            UPDATES
+        );
+        #[cfg(feature = "bibliography")]
+        assert_eq!(
+            parse_publications(crate::macros::include_content_str!(
+                "publications/bibliography.bib"
+            )),
+            PUBLICATIONS
+        );
+    }
+}
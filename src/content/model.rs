@@ -0,0 +1,98 @@
+//! Data model of the content, which is shared with the build script.
+//!
+//! All text is stored as [`Text`] so that the same types can hold both the static data generated
+//! at build time and data that is parsed at runtime.
+
+use std::borrow::Cow;
+
+/// Text that is either embedded in the binary or owned.
+pub type Text = Cow<'static, str>;
+
+/// Update shown on the timeline of the About page.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Update {
+    pub date: chrono::NaiveDate,
+    pub title: Text,
+    pub highlights: Text,
+    pub description: Text,
+    pub url: Text,
+}
+
+/// Entry of the bibliography.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Publication {
+    /// Citation key, e.g. `orsula2022learning`
+    pub key: Text,
+    /// Type of the entry in lowercase, e.g. `inproceedings`
+    pub entry_type: Text,
+    pub title: Text,
+    pub authors: Cow<'static, [Person]>,
+    pub date: PublicationDate,
+    /// Booktitle, type or journal of the publication
+    pub venue: Option<Text>,
+    pub details: PublicationDetails,
+    /// Abstract of the publication
+    pub summary: Option<Text>,
+    /// Keywords of the publication, e.g. `space robotics`
+    pub keywords: Cow<'static, [Text]>,
+    pub links: PublicationLinks,
+    /// BibTeX entry without the custom link fields
+    pub bibtex: Text,
+}
+
+/// Author of a publication.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Person {
+    pub given_name: Text,
+    pub name: Text,
+}
+
+/// Date of a publication with an optional month and day (both starting at one).
+///
+/// Dates are ordered chronologically, and a date without a month or day precedes the dates
+/// that specify them within the same year or month. Forthcoming publications without a year
+/// (e.g. `year = {in press}`) follow all others.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PublicationDate {
+    pub year: Option<i32>,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+}
+
+impl Ord for PublicationDate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let key = |date: &Self| (date.year.is_none(), date.year, date.month, date.day);
+        key(self).cmp(&key(other))
+    }
+}
+
+impl PartialOrd for PublicationDate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Bibliographic details of a publication that are used to format its citations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublicationDetails {
+    pub volume: Option<Text>,
+    pub number: Option<Text>,
+    /// Page range, e.g. `4112--4119`
+    pub pages: Option<Text>,
+    /// Publisher, organization, school or institution
+    pub publisher: Option<Text>,
+    pub doi: Option<Text>,
+}
+
+/// Links of a publication that are stored in custom BibTeX fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublicationLinks {
+    pub homepage: Option<Text>,
+    pub publication_url: Option<Text>,
+    pub article_pdf: Option<Text>,
+    pub article_html: Option<Text>,
+    pub video: Option<Text>,
+    pub presentation: Option<Text>,
+    pub repository: Option<Text>,
+}
@@ -0,0 +1,438 @@
+//! Parsers of the content files, which are shared with the build script.
+
+use biblatex::ChunksExt;
+
+use super::model::{
+    Person, Publication, PublicationDate, PublicationDetails, PublicationLinks, Text, Update,
+};
+
+/// Custom BibTeX fields of the links (including their aliases) that are stripped from the
+/// copied entries.
+const CUSTOM_BIB_FIELDS: &[&str] = &[
+    "homepage",
+    "website",
+    "publication_url",
+    "url",
+    "article",
+    "article_pdf",
+    "pdf",
+    "article_html",
+    "html",
+    "video",
+    "short_video",
+    "presentation",
+    "video_presentation",
+    "repository",
+    "code",
+];
+
+/// Error in a content file with its location (both line and column start at one).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+/// What is wrong with the content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The file is not valid YAML or does not match the expected structure
+    Yaml(String),
+    /// The file is not valid BibTeX
+    Bibtex(String),
+    /// A bibliography entry lacks a required field
+    MissingField { key: String, field: String },
+    /// A field of a bibliography entry cannot be parsed
+    MalformedField { key: String, message: String },
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl std::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Yaml(message) | Self::Bibtex(message) => f.write_str(message),
+            Self::MissingField { key, field } => {
+                write!(f, "entry `{key}` is missing the `{field}` field")
+            }
+            Self::MalformedField { key, message } => {
+                write!(f, "entry `{key}` has a malformed field: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// Error at the given byte offset into the source.
+    fn at(src: &str, offset: usize, kind: ParseErrorKind) -> Self {
+        let before = src.get(..offset).unwrap_or(src);
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            kind,
+        }
+    }
+
+    /// Key of the bibliography entry that the error belongs to, if any.
+    pub fn key(&self) -> Option<&str> {
+        match &self.kind {
+            ParseErrorKind::Yaml(_) | ParseErrorKind::Bibtex(_) => None,
+            ParseErrorKind::MissingField { key, .. }
+            | ParseErrorKind::MalformedField { key, .. } => Some(key),
+        }
+    }
+}
+
+/// Parse the updates from YAML, newest first.
+pub fn updates(src: &str) -> Result<Vec<Update>, ParseError> {
+    let mut updates: Vec<Update> = yaml(src)?;
+    updates.sort_by_key(|update| std::cmp::Reverse(update.date));
+    Ok(updates)
+}
+
+/// Parse any YAML content, e.g. the short links that are validated by the build script.
+pub fn yaml<T: serde::de::DeserializeOwned>(src: &str) -> Result<T, ParseError> {
+    serde_yaml::from_str(src).map_err(|err| {
+        let (line, column) = err
+            .location()
+            .map_or((1, 1), |location| (location.line(), location.column()));
+        // The location is already part of the error, so it is not repeated in the message
+        let message = err.to_string();
+        let message = message
+            .split_once(" at line ")
+            .map_or(message.as_str(), |(message, _)| message);
+        ParseError {
+            line,
+            column,
+            kind: ParseErrorKind::Yaml(message.to_owned()),
+        }
+    })
+}
+
+/// Parse the publications from BibTeX in the order of the source.
+///
+/// Only invalid BibTeX fails the whole file. Entries that are malformed are returned as errors
+/// in their place, so that the remaining entries can still be shown.
+pub fn publications(src: &str) -> Result<Vec<Result<Publication, ParseError>>, ParseError> {
+    Ok(biblatex::Bibliography::parse(src)
+        .map_err(|err| {
+            ParseError::at(
+                src,
+                err.span.start,
+                ParseErrorKind::Bibtex(err.kind.to_string()),
+            )
+        })?
+        .into_iter()
+        .map(|entry| publication(src, entry))
+        .collect())
+}
+
+fn publication(src: &str, entry: biblatex::Entry) -> Result<Publication, ParseError> {
+    let error = |err: biblatex::RetrievalError| match err {
+        biblatex::RetrievalError::Missing(field) => ParseError::at(
+            src,
+            entry_offset(src, &entry.key),
+            ParseErrorKind::MissingField {
+                key: entry.key.clone(),
+                field,
+            },
+        ),
+        biblatex::RetrievalError::TypeError(err) => ParseError::at(
+            src,
+            err.span.start,
+            ParseErrorKind::MalformedField {
+                key: entry.key.clone(),
+                message: err.kind.to_string(),
+            },
+        ),
+    };
+
+    let date = match entry.date() {
+        Ok(biblatex::PermissiveType::Typed(date)) => match date.value {
+            biblatex::DateValue::At(date)
+            | biblatex::DateValue::After(date)
+            | biblatex::DateValue::Before(date)
+            | biblatex::DateValue::Between(date, _) => PublicationDate {
+                year: Some(date.year),
+                month: date.month.map(|month| month + 1),
+                day: date.day.map(|day| day + 1),
+            },
+        },
+        // Free-form dates (e.g. `date = {2024-spring}` or `year = {in press}`) only contribute
+        // the year that they mention, if any
+        Ok(biblatex::PermissiveType::Chunks(chunks)) => free_form_date(&chunks.format_verbatim()),
+        Err(biblatex::RetrievalError::TypeError(_)) => free_form_date(
+            &["date", "year"]
+                .iter()
+                .find_map(|field| entry.get(field))
+                .map(|chunks| chunks.format_verbatim())
+                .unwrap_or_default(),
+        ),
+        // Forthcoming publications might not have a date yet
+        Err(biblatex::RetrievalError::Missing(_)) => PublicationDate::default(),
+    };
+
+    // Copied entries only contain the standard fields
+    let mut raw_entry = entry.clone();
+    for field in CUSTOM_BIB_FIELDS {
+        raw_entry.remove(field);
+    }
+    let bibtex = raw_entry
+        .to_bibtex_string()
+        .unwrap_or_else(|_| raw_entry.to_biblatex_string());
+
+    let field = |fields: &[&str]| -> Option<Text> {
+        fields
+            .iter()
+            .find_map(|field| entry.get(field))
+            .map(|chunks| chunks.format_verbatim().into())
+    };
+
+    Ok(Publication {
+        title: entry.title().map_err(error)?.format_verbatim().into(),
+        authors: entry
+            .author()
+            .map_err(error)?
+            .into_iter()
+            .map(|person| Person {
+                given_name: person.given_name.into(),
+                name: person.name.into(),
+            })
+            .collect(),
+        date,
+        venue: field(&["booktitle", "type", "journal", "journaltitle"]),
+        details: PublicationDetails {
+            volume: field(&["volume"]),
+            number: field(&["number", "issue"]),
+            pages: field(&["pages", "page"]),
+            publisher: field(&["publisher", "organization", "school", "institution"]),
+            doi: field(&["doi"]),
+        },
+        summary: field(&["abstract"]),
+        keywords: field(&["keywords"])
+            .map(|keywords| {
+                keywords
+                    .split([',', ';'])
+                    .map(str::trim)
+                    .filter(|keyword| !keyword.is_empty())
+                    .map(|keyword| keyword.to_owned().into())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        links: PublicationLinks {
+            homepage: field(&["homepage", "website"]),
+            publication_url: field(&["publication_url", "url"]),
+            article_pdf: field(&["article", "article_pdf", "pdf"]),
+            article_html: field(&["article_html", "html"]),
+            video: field(&["video", "short_video"]),
+            presentation: field(&["presentation", "video_presentation"]),
+            repository: field(&["repository", "code"]),
+        },
+        bibtex: bibtex.into(),
+        entry_type: match &entry.entry_type {
+            biblatex::EntryType::Unknown(entry_type) => entry_type.clone(),
+            entry_type => entry_type.to_string(),
+        }
+        .into(),
+        key: entry.key.into(),
+    })
+}
+
+/// Date with the first four-digit number of a free-form date as its year.
+fn free_form_date(date: &str) -> PublicationDate {
+    let year = date
+        .split(|c: char| !c.is_ascii_digit())
+        .find(|digits| digits.len() == 4)
+        .and_then(|digits| digits.parse().ok());
+    PublicationDate {
+        year,
+        ..PublicationDate::default()
+    }
+}
+
+/// Byte offset of the entry with the given key, e.g. `@article{key,`.
+fn entry_offset(src: &str, key: &str) -> usize {
+    src.match_indices('@')
+        .find(|&(offset, _)| {
+            src[offset..]
+                .split_once('{')
+                .and_then(|(_, rest)| rest.split_once(','))
+                .is_some_and(|(entry_key, _)| entry_key.trim() == key)
+        })
+        .map_or(0, |(offset, _)| offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_updates() {
+        const YAML: &str = indoc::indoc! {r#"
+            - date: 2024-12-25
+              title: "AoC 2024"
+              highlights: "Challenge"
+              description: "Solved Advent of Code 2024."
+              url: "https://adventofcode.com/2024"
+
+            - date: 2025-05-17
+              title: "ICRA 2025"
+              highlights: "Presentation"
+              description: "Presented at ICRA 2025."
+              url: "https://youtu.be/e9VoTEUscqc"
+        "#};
+
+        let updates = updates(YAML).unwrap();
+        assert_eq!(
+            updates
+                .iter()
+                .map(|update| &update.title)
+                .collect::<Vec<_>>(),
+            ["ICRA 2025", "AoC 2024"]
+        );
+        assert_eq!(
+            updates[0].date,
+            chrono::NaiveDate::from_ymd_opt(2025, 5, 17).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_publications() {
+        const BIB: &str = indoc::indoc! {"
+            @inproceedings{orsula2022learning,
+                title = {{Learning to Grasp on the Moon from 3D Octree Observations with Deep Reinforcement Learning}},
+                author = {Andrej Orsula and Simon Bøgh and Miguel Olivares-Mendez and Carol Martinez},
+                booktitle = {2022 IEEE/RSJ International Conference on Intelligent Robots and Systems (IROS)},
+                year = {2022},
+                organization = {IEEE},
+                page = {4112--4119},
+                keywords = {space robotics; grasping, },
+                abstract = {We learn to grasp.},
+                repository = {https://github.com/AndrejOrsula/drl_grasping},
+                article_pdf = {https://arxiv.org/pdf/2208.00818},
+                publication_url = {https://ieeexplore.ieee.org/document/9981661},
+            }
+        "};
+
+        let publications = publications(BIB).unwrap();
+        let publication = publications[0].as_ref().unwrap();
+
+        assert_eq!(publication.key, "orsula2022learning");
+        assert_eq!(publication.entry_type, "inproceedings");
+        assert_eq!(publication.title, "Learning to Grasp on the Moon from 3D Octree Observations with Deep Reinforcement Learning");
+        assert_eq!(
+            publication
+                .authors
+                .iter()
+                .map(|author| format!("{} {}", author.given_name, author.name))
+                .collect::<Vec<_>>(),
+            [
+                "Andrej Orsula",
+                "Simon Bøgh",
+                "Miguel Olivares-Mendez",
+                "Carol Martinez"
+            ]
+        );
+        assert_eq!(publication.date.year, Some(2022));
+        assert_eq!(
+            publication.venue.as_deref(),
+            Some("2022 IEEE/RSJ International Conference on Intelligent Robots and Systems (IROS)")
+        );
+        assert_eq!(publication.details.pages.as_deref(), Some("4112–4119"));
+        assert_eq!(publication.details.publisher.as_deref(), Some("IEEE"));
+        assert_eq!(
+            publication.keywords.as_ref(),
+            ["space robotics", "grasping"]
+        );
+        assert_eq!(publication.links.homepage, None);
+        assert_eq!(
+            publication.links.publication_url.as_deref(),
+            Some("https://ieeexplore.ieee.org/document/9981661")
+        );
+        assert_eq!(
+            publication.links.article_pdf.as_deref(),
+            Some("https://arxiv.org/pdf/2208.00818")
+        );
+        assert_eq!(publication.links.article_html, None);
+        assert_eq!(
+            publication.links.repository.as_deref(),
+            Some("https://github.com/AndrejOrsula/drl_grasping")
+        );
+        assert!(publication.bibtex.contains("organization = {IEEE}"));
+        assert!(!publication.bibtex.contains("repository"));
+    }
+
+    #[test]
+    fn parse_free_form_dates() {
+        let publications = publications(indoc::indoc! {"
+            @article{season,
+                title = {Season},
+                author = {Doe, Jane},
+                date = {2024-spring},
+            }
+
+            @article{press,
+                title = {Press},
+                author = {Doe, Jane},
+                year = {in press},
+            }
+
+            @unpublished{undated,
+                title = {Undated},
+                author = {Doe, Jane},
+            }
+        "})
+        .unwrap();
+        assert_eq!(
+            publications
+                .iter()
+                .map(|publication| publication.as_ref().unwrap().date.year)
+                .collect::<Vec<_>>(),
+            [Some(2024), None, None]
+        );
+    }
+
+    #[test]
+    fn report_error_locations() {
+        let err = updates("- date: 2025-13-01\n  title: Invalid\n").unwrap_err();
+        assert_eq!(err.line, 1);
+
+        // Only the malformed entry is an error
+        let entries = publications(indoc::indoc! {"
+            @article{first,
+                title = {First},
+                author = {Doe, Jane},
+                year = {2024},
+            }
+
+            @article{second,
+                author = {Doe, Jane},
+                year = {2024},
+            }
+        "})
+        .unwrap();
+        assert!(entries[0].is_ok());
+        let err = entries[1].as_ref().unwrap_err();
+        assert_eq!((err.line, err.column), (7, 1));
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::MissingField {
+                key: "second".to_owned(),
+                field: "title".to_owned()
+            }
+        );
+        assert_eq!(err.key(), Some("second"));
+
+        let err = publications("@article{broken,\n  title = {Unclosed,\n").unwrap_err();
+        assert_eq!(err.line, 3);
+        assert!(matches!(err.kind, ParseErrorKind::Bibtex(_)));
+    }
+}
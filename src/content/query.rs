@@ -0,0 +1,392 @@
+//! Sorting and querying of the content.
+
+use itertools::Itertools;
+
+use super::model::{Person, Publication};
+
+impl Person {
+    /// Full name, e.g. `Simon Bøgh`.
+    pub fn full_name(&self) -> String {
+        if self.given_name.is_empty() {
+            self.name.to_string()
+        } else {
+            format!("{} {}", self.given_name, self.name)
+        }
+    }
+
+    /// Name with the given name abbreviated to its initial, e.g. `S Bøgh`.
+    pub fn short_name(&self) -> String {
+        match self.given_name.chars().next() {
+            Some(initial) => format!("{initial} {}", self.name),
+            None => self.name.to_string(),
+        }
+    }
+
+    /// Whether the person has the given surname and a given name that starts with the first of
+    /// the given initials.
+    pub fn is(&self, initials: &str, surname: &str) -> bool {
+        self.name == surname
+            && initials
+                .chars()
+                .next()
+                .is_some_and(|initial| self.given_name.starts_with(initial))
+    }
+}
+
+impl Publication {
+    /// Index of the author with the given initials and surname, if they are among the authors.
+    pub fn author_index(&self, initials: &str, surname: &str) -> Option<usize> {
+        self.authors
+            .iter()
+            .position(|author| author.is(initials, surname))
+    }
+
+    /// Links to the videos of the publication with their titles.
+    pub fn videos(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        [
+            ("Video summary", &self.links.video),
+            ("Full presentation", &self.links.presentation),
+        ]
+        .into_iter()
+        .filter_map(|(title, link)| Some((title, link.as_deref()?)))
+    }
+
+    /// Whether the publication was presented in a talk with a recording.
+    pub fn is_talk(&self) -> bool {
+        self.links.presentation.is_some()
+    }
+
+    /// Short form of the venue, e.g. `IROS 2022` for a venue that ends with its abbreviation in
+    /// parentheses.
+    pub fn short_venue(&self) -> Option<String> {
+        let venue = self.venue.as_deref()?;
+        let name = self.venue_name()?;
+        Some(match self.date.year {
+            Some(year) if venue_abbreviation(venue).is_some() => format!("{name} {year}"),
+            _ => name,
+        })
+    }
+
+    /// Name of the venue that is shared by all of its editions, e.g. `IROS` for a venue that ends
+    /// with its abbreviation in parentheses or `IEEE Aerospace Conference` for
+    /// `2023 IEEE Aerospace Conference`.
+    pub fn venue_name(&self) -> Option<String> {
+        let venue = self.venue.as_deref()?;
+        let lowercase = venue.to_lowercase();
+        Some(if let Some(abbreviation) = venue_abbreviation(venue) {
+            abbreviation.to_owned()
+        } else if lowercase.contains("arxiv") {
+            "arXiv preprint".to_owned()
+        } else if lowercase.contains("preprint") {
+            "Preprint".to_owned()
+        } else {
+            // Drop the year of the edition, e.g. `2023 IEEE Aerospace Conference`
+            venue
+                .split_whitespace()
+                .filter(|word| !(word.len() == 4 && word.chars().all(|c| c.is_ascii_digit())))
+                .join(" ")
+        })
+    }
+
+    pub fn kind(&self) -> PublicationKind {
+        let venue = self.venue.as_deref().unwrap_or_default().to_lowercase();
+        match self.entry_type.as_ref() {
+            _ if venue.contains("arxiv") || venue.contains("preprint") => PublicationKind::Preprint,
+            "unpublished" => PublicationKind::Preprint,
+            "article" => PublicationKind::Journal,
+            "inproceedings" | "proceedings" => PublicationKind::Conference,
+            "thesis" | "mastersthesis" | "phdthesis" => PublicationKind::Thesis,
+            _ => PublicationKind::Other,
+        }
+    }
+}
+
+/// Abbreviation of a venue that ends with it in parentheses, e.g. `IROS`.
+fn venue_abbreviation(venue: &str) -> Option<&str> {
+    let (_, abbreviation) = venue.strip_suffix(')')?.rsplit_once('(')?;
+    Some(abbreviation)
+}
+
+/// Category of a publication that is derived from its type and venue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PublicationKind {
+    Journal,
+    Conference,
+    Thesis,
+    Preprint,
+    Other,
+}
+
+impl PublicationKind {
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Journal => "Journal articles",
+            Self::Conference => "Conference papers",
+            Self::Thesis => "Theses",
+            Self::Preprint => "Preprints",
+            Self::Other => "Other",
+        }
+    }
+
+    /// Identifier of the kind in URLs, e.g. `journal`.
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::Journal => "journal",
+            Self::Conference => "conference",
+            Self::Thesis => "thesis",
+            Self::Preprint => "preprint",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// Order in which the publications are listed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortMode {
+    #[default]
+    Newest,
+    Oldest,
+    Venue,
+    Kind,
+}
+
+impl SortMode {
+    pub const ALL: &[Self] = &[Self::Newest, Self::Oldest, Self::Venue, Self::Kind];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Newest => "Newest first",
+            Self::Oldest => "Oldest first",
+            Self::Venue => "Venue",
+            Self::Kind => "Type",
+        }
+    }
+
+    /// Compare two publications, breaking ties by their type and then by their key so that the
+    /// order is stable.
+    pub fn compare(self, a: &Publication, b: &Publication) -> std::cmp::Ordering {
+        let newest = || b.date.cmp(&a.date);
+        let venue = |publication: &Publication| {
+            // Publications without a venue are listed last
+            publication
+                .venue_name()
+                .map_or((true, String::new()), |venue| (false, venue.to_lowercase()))
+        };
+        match self {
+            Self::Newest => newest(),
+            Self::Oldest => a.date.cmp(&b.date),
+            Self::Venue => venue(a).cmp(&venue(b)).then_with(newest),
+            Self::Kind => a.kind().cmp(&b.kind()).then_with(newest),
+        }
+        .then_with(|| a.entry_type.cmp(&b.entry_type))
+        .then_with(|| a.key.cmp(&b.key))
+    }
+
+    /// Heading of the group that the publication is listed under, e.g. its year.
+    pub fn group(self, publication: &Publication) -> String {
+        match self {
+            Self::Newest | Self::Oldest => publication.date.year.map_or_else(
+                || "In press / Forthcoming".to_owned(),
+                |year| year.to_string(),
+            ),
+            Self::Venue => publication
+                .venue_name()
+                .unwrap_or_else(|| "Other".to_owned()),
+            Self::Kind => publication.kind().title().to_owned(),
+        }
+    }
+}
+
+/// URL of the preview image of a YouTube video, e.g. for `https://youtube.com/watch?v=<id>` or
+/// `https://youtu.be/<id>`.
+pub fn youtube_thumbnail(url: &str) -> Option<String> {
+    let url = url.split_once("://").map_or(url, |(_, url)| url);
+    let url = url.strip_prefix("www.").unwrap_or(url);
+    let id = if let Some(path) = url.strip_prefix("youtu.be/") {
+        path
+    } else if let Some(query) = url.strip_prefix("youtube.com/watch?") {
+        query
+            .split('&')
+            .find_map(|param| param.strip_prefix("v="))?
+    } else {
+        url.strip_prefix("youtube.com/embed/")?
+    };
+    let id = id.split(['?', '&', '/', '#']).next()?;
+    (!id.is_empty()).then(|| format!("https://img.youtube.com/vi/{id}/hqdefault.jpg"))
+}
+
+/// Thumbnail of the publication with the given key from `content/publications/thumbnails`, as
+/// its file name and contents.
+#[cfg(not(any(hot_reload, feature = "remote-content")))]
+pub fn embedded_thumbnail(key: &str) -> Option<(&'static str, &'static [u8])> {
+    static THUMBNAILS: include_dir::Dir =
+        include_dir::include_dir!("$CARGO_MANIFEST_DIR/content/publications/thumbnails");
+    THUMBNAILS
+        .files()
+        .find(|file| file.path().file_stem().is_some_and(|stem| stem == key))
+        .and_then(|file| Some((file.path().file_name()?.to_str()?, file.contents())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{PublicationDate, PublicationDetails, PublicationLinks};
+
+    fn publication(key: &'static str, year: i32, venue: Option<&'static str>) -> Publication {
+        Publication {
+            key: key.into(),
+            entry_type: "inproceedings".into(),
+            title: "Title".into(),
+            authors: vec![
+                Person {
+                    given_name: "Simon".into(),
+                    name: "Bøgh".into(),
+                },
+                Person {
+                    given_name: "Andrej".into(),
+                    name: "Orsula".into(),
+                },
+            ]
+            .into(),
+            date: PublicationDate {
+                year: Some(year),
+                month: None,
+                day: None,
+            },
+            venue: venue.map(Into::into),
+            details: PublicationDetails::default(),
+            summary: None,
+            keywords: Default::default(),
+            links: PublicationLinks::default(),
+            bibtex: "".into(),
+        }
+    }
+
+    #[test]
+    fn query_publications() {
+        let iros = publication(
+            "iros",
+            2022,
+            Some("2022 IEEE/RSJ International Conference on Intelligent Robots and Systems (IROS)"),
+        );
+        assert_eq!(iros.author_index("AO", "Orsula"), Some(1));
+        assert_eq!(iros.author_index("JD", "Orsula"), None);
+        assert_eq!(iros.authors[0].short_name(), "S Bøgh");
+        assert_eq!(iros.short_venue().as_deref(), Some("IROS 2022"));
+        assert!(!iros.is_talk());
+
+        let preprint = publication("preprint", 2024, Some("arXiv preprint arXiv:2401.00001"));
+        assert_eq!(preprint.short_venue().as_deref(), Some("arXiv preprint"));
+        assert_eq!(publication("none", 2024, None).short_venue(), None);
+        let aerospace = publication("aerospace", 2023, Some("2023 IEEE Aerospace Conference"));
+        assert_eq!(
+            aerospace.short_venue().as_deref(),
+            Some("IEEE Aerospace Conference")
+        );
+
+        assert_eq!(iros.kind(), PublicationKind::Conference);
+        assert_eq!(preprint.kind(), PublicationKind::Preprint);
+
+        assert_eq!(
+            youtube_thumbnail("https://youtube.com/watch?v=1-cudiW4eaU&list=PLzc").as_deref(),
+            Some("https://img.youtube.com/vi/1-cudiW4eaU/hqdefault.jpg")
+        );
+        assert_eq!(
+            youtube_thumbnail("https://youtu.be/FZSoOkK6VFc?t=42").as_deref(),
+            Some("https://img.youtube.com/vi/FZSoOkK6VFc/hqdefault.jpg")
+        );
+        assert_eq!(youtube_thumbnail("https://vimeo.com/123"), None);
+    }
+
+    #[test]
+    fn sort_publications() {
+        let dated = |key, year, month, day| Publication {
+            date: PublicationDate {
+                year: Some(year),
+                month,
+                day,
+            },
+            ..publication(key, year, None)
+        };
+        let mut publications = vec![
+            dated("october", 2024, Some(10), Some(1)),
+            dated("january", 2024, Some(1), Some(15)),
+            dated("year", 2024, None, None),
+            Publication {
+                entry_type: "article".into(),
+                ..dated("article", 2024, Some(1), Some(15))
+            },
+            dated("older", 2023, Some(12), None),
+            Publication {
+                date: PublicationDate::default(),
+                ..publication("forthcoming", 0, None)
+            },
+        ];
+        let keys = |publications: &[Publication]| {
+            publications
+                .iter()
+                .map(|publication| publication.key.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        publications.sort_by(|a, b| SortMode::Newest.compare(a, b));
+        assert_eq!(
+            keys(&publications),
+            [
+                "forthcoming",
+                "october",
+                "article",
+                "january",
+                "year",
+                "older"
+            ]
+        );
+        publications.sort_by(|a, b| SortMode::Oldest.compare(a, b));
+        assert_eq!(
+            keys(&publications),
+            [
+                "older",
+                "year",
+                "article",
+                "january",
+                "october",
+                "forthcoming"
+            ]
+        );
+        assert_eq!(
+            SortMode::Oldest.group(&publications[5]),
+            "In press / Forthcoming"
+        );
+        publications.sort_by(|a, b| SortMode::Kind.compare(a, b));
+        assert_eq!(keys(&publications)[0], "article");
+        assert_eq!(SortMode::Kind.group(&publications[0]), "Journal articles");
+
+        // Editions of the same venue in different years share a group
+        let mut publications = vec![
+            publication(
+                "iros2022",
+                2022,
+                Some("2022 IEEE/RSJ International Conference on Intelligent Robots and Systems (IROS)"),
+            ),
+            publication("aerospace", 2023, Some("2023 IEEE Aerospace Conference")),
+            publication(
+                "iros2024",
+                2024,
+                Some("2024 IEEE/RSJ International Conference on Intelligent Robots and Systems (IROS)"),
+            ),
+            publication("none", 2024, None),
+        ];
+        publications.sort_by(|a, b| SortMode::Venue.compare(a, b));
+        assert_eq!(
+            keys(&publications),
+            ["aerospace", "iros2024", "iros2022", "none"]
+        );
+        assert_eq!(
+            publications
+                .iter()
+                .map(|publication| SortMode::Venue.group(publication))
+                .collect::<Vec<_>>(),
+            ["IEEE Aerospace Conference", "IROS", "IROS", "Other"]
+        );
+    }
+}
@@ -1,10 +1,13 @@
 pub use app::App;
-pub use consts::*;
+pub use site::{site, SiteConfig};
 
 mod app;
-mod consts;
+mod content;
 mod macros;
+mod navigation;
 mod page;
+mod persistence;
+mod site;
 mod social;
 mod style;
 mod utils;
@@ -1,9 +1,13 @@
+/// Text of `content/$file`, which is only embedded by the tests if the content is fetched from the
+/// server.
+#[cfg(any(test, not(feature = "remote-content")))]
 macro_rules! include_content_str {
     ($file:expr $(,)?) => {
         include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/content/", $file))
     };
 }
 
+#[cfg(not(any(hot_reload, feature = "remote-content")))]
 macro_rules! include_content_image {
     ($file:expr $(,)?) => {
         ::egui::ImageSource::Bytes {
@@ -17,6 +21,42 @@ macro_rules! include_content_image {
     };
 }
 
+/// The image is loaded at runtime instead if it is fetched from the server or hot-reloaded.
+#[cfg(any(hot_reload, feature = "remote-content"))]
+macro_rules! include_content_image {
+    ($file:expr $(,)?) => {
+        $crate::utils::loading::content_image($file)
+    };
+}
+
+/// Content that is embedded in the app, or fetched from `content/$file` at runtime with the
+/// `remote-content` feature (in which case the embedded expression is not evaluated). The
+/// embedded content is hot-reloaded from the same file in debug builds of the native app.
+macro_rules! load_content {
+    ($file:expr, embedded: $embedded:expr, parse: $parse:expr $(,)?) => {{
+        #[cfg(not(feature = "remote-content"))]
+        let content = $crate::utils::loading::Loadable::ready($embedded);
+        #[cfg(hot_reload)]
+        let content = content.with_hot_reload($file, $parse);
+        #[cfg(feature = "remote-content")]
+        let content = $crate::utils::loading::Loadable::fetch($file, $parse);
+        content
+    }};
+}
+
+/// Markdown document that is loaded via [`load_content`].
+macro_rules! load_markdown {
+    ($file:expr $(,)?) => {
+        $crate::macros::load_content!(
+            $file,
+            embedded: $crate::utils::markdown::MarkdownDocument::new(
+                $crate::macros::include_content_str!($file)
+            ),
+            parse: |src| Ok($crate::utils::markdown::MarkdownDocument::new(src.to_owned())),
+        )
+    };
+}
+
 macro_rules! include_assets_font {
     ($file:expr $(,)?) => {
         ::egui::FontData::from_static(include_bytes!(concat!(
@@ -27,4 +67,6 @@ macro_rules! include_assets_font {
     };
 }
 
-pub(crate) use {include_assets_font, include_content_image, include_content_str};
+#[cfg(any(test, not(feature = "remote-content")))]
+pub(crate) use include_content_str;
+pub(crate) use {include_assets_font, include_content_image, load_content, load_markdown};
@@ -67,7 +67,7 @@ fn main() -> eframe::Result<()> {
     };
 
     eframe::run_native(
-        portfolio_andrejorsula::AUTHOR_NAME_FULL,
+        &portfolio_andrejorsula::site().author.name,
         native_options,
         Box::new(|cc| Ok(Box::new(portfolio_andrejorsula::App::new(cc)))),
     )
@@ -0,0 +1,61 @@
+use super::Target;
+
+/// Duration of the highlight of a targeted entry in seconds.
+const HIGHLIGHT_DURATION: f64 = 2.0;
+
+/// Entry that should be scrolled into view and highlighted.
+#[derive(Debug, Clone)]
+struct Focus {
+    target: Target,
+    /// Time at which the entry was first shown
+    shown_at: Option<f64>,
+}
+
+fn focus_id() -> egui::Id {
+    egui::Id::new("navigation_focus")
+}
+
+/// Request the entry identified by the target to be scrolled into view and highlighted.
+pub(super) fn set(ctx: &egui::Context, target: Target) {
+    ctx.data_mut(|data| {
+        data.insert_temp(
+            focus_id(),
+            Focus {
+                target,
+                shown_at: None,
+            },
+        );
+    });
+}
+
+/// Scroll to and highlight the area of an entry if it is the currently focused target.
+pub fn highlight_if_targeted(ui: &egui::Ui, rect: egui::Rect, target: &Target) {
+    let Some(mut focus) = ui.data(|data| data.get_temp::<Focus>(focus_id())) else {
+        return;
+    };
+    if &focus.target != target {
+        return;
+    }
+
+    let now = ui.input(|i| i.time);
+    let shown_at = *focus.shown_at.get_or_insert_with(|| {
+        ui.scroll_to_rect(rect, Some(egui::Align::Center));
+        now
+    });
+
+    let progress = ((now - shown_at) / HIGHLIGHT_DURATION) as f32;
+    if progress < 1.0 {
+        ui.painter().rect_filled(
+            rect.expand(ui.spacing().item_spacing.y),
+            ui.visuals().menu_rounding,
+            ui.visuals()
+                .selection
+                .bg_fill
+                .gamma_multiply(1.0 - progress),
+        );
+        ui.data_mut(|data| data.insert_temp(focus_id(), focus));
+        ui.ctx().request_repaint();
+    } else {
+        ui.data_mut(|data| data.remove::<Focus>(focus_id()));
+    }
+}
@@ -0,0 +1,154 @@
+/// Maximum duration of a swipe in seconds.
+const MAX_SWIPE_DURATION: f64 = 0.6;
+/// Minimum horizontal distance of a swipe in points.
+const MIN_SWIPE_DISTANCE: f32 = 80.0;
+
+/// Step to the previous (-1) or next (+1) page requested via arrow keys or a horizontal swipe.
+pub(super) fn page_step(ctx: &egui::Context, swipe: &mut SwipeDetector) -> Option<isize> {
+    // Arrow keys are left to widgets that have keyboard focus (e.g. text fields)
+    if ctx.memory(|m| m.focused().is_none()) {
+        let (previous, next) = ctx.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowLeft),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowRight),
+            )
+        });
+        if previous {
+            return Some(-1);
+        } else if next {
+            return Some(1);
+        }
+    }
+
+    let page_drag_id = super::scroll::ScrollState::load(ctx).and_then(|state| state.drag_id);
+    swipe.update(ctx, page_drag_id)
+}
+
+/// Detector of horizontal swipes on touch screens.
+#[derive(Debug, Default)]
+pub(super) struct SwipeDetector {
+    /// Position and time at which the touch started
+    start: Option<(egui::Pos2, f64)>,
+}
+
+impl SwipeDetector {
+    /// Detect a swipe, unless a widget other than the page with the given drag id is dragged
+    /// (e.g. a slider or a horizontally scrolled code block).
+    fn update(&mut self, ctx: &egui::Context, page_drag_id: Option<egui::Id>) -> Option<isize> {
+        if [ctx.dragged_id(), ctx.drag_stopped_id()]
+            .into_iter()
+            .flatten()
+            .any(|id| Some(id) != page_drag_id)
+        {
+            self.start = None;
+        }
+
+        ctx.input(|i| {
+            if i.pointer.primary_pressed() && i.any_touches() {
+                self.start = i.pointer.press_origin().map(|pos| (pos, i.time));
+                return None;
+            }
+            if !i.pointer.primary_released() {
+                return None;
+            }
+
+            let (start_pos, start_time) = self.start.take()?;
+            let delta = i.pointer.latest_pos()? - start_pos;
+            (i.time - start_time <= MAX_SWIPE_DURATION
+                && delta.x.abs() >= MIN_SWIPE_DISTANCE
+                && delta.x.abs() >= 2.0 * delta.y.abs())
+            .then_some(if delta.x < 0.0 { 1 } else { -1 })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Swipe from right to left across a scrolled page, optionally across a slider on it.
+    fn swipe(with_slider: bool) -> Option<isize> {
+        let ctx = egui::Context::default();
+        let mut detector = SwipeDetector::default();
+        let slider_rect = std::cell::Cell::new(egui::Rect::NOTHING);
+        let mut frame = |events: Vec<egui::Event>, time: f64| {
+            let input = egui::RawInput {
+                events,
+                time: Some(time),
+                screen_rect: Some(egui::Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    egui::vec2(400.0, 800.0),
+                )),
+                ..Default::default()
+            };
+            let mut step = None;
+            let _ = ctx.run(input, |ctx| {
+                if super::super::scroll::ScrollState::load(ctx).is_none() {
+                    super::super::scroll::ScrollState::restoring("page".to_owned(), None)
+                        .store(ctx);
+                }
+                step = page_step(ctx, &mut detector);
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let output = crate::navigation::scroll_area(ctx).show(ui, |ui| {
+                        if with_slider {
+                            ui.spacing_mut().slider_width = 400.0;
+                            slider_rect.set(ui.add(egui::Slider::new(&mut 0.0, 0.0..=1.0)).rect);
+                        }
+                        ui.allocate_space(egui::vec2(500.0, 2000.0));
+                    });
+                    crate::navigation::remember_scroll_offset(ctx, &output);
+                });
+            });
+            step
+        };
+        let touch = |phase, pos| egui::Event::Touch {
+            device_id: egui::TouchDeviceId(0),
+            id: egui::TouchId(0),
+            phase,
+            pos,
+            force: None,
+        };
+        let button = |pressed, pos| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+
+        // The scroll area only becomes draggable once it knows that its content overflows
+        frame(Vec::new(), 0.0);
+        frame(Vec::new(), 0.05);
+        let start = egui::pos2(300.0, 20.0);
+        assert!(!with_slider || slider_rect.get().contains(start));
+        let mut step = frame(
+            vec![
+                touch(egui::TouchPhase::Start, start),
+                egui::Event::PointerMoved(start),
+                button(true, start),
+            ],
+            0.1,
+        );
+        for (i, x) in [250.0, 200.0, 150.0].into_iter().enumerate() {
+            let pos = egui::pos2(x, start.y);
+            step = step.or(frame(
+                vec![
+                    touch(egui::TouchPhase::Move, pos),
+                    egui::Event::PointerMoved(pos),
+                ],
+                0.2 + 0.05 * i as f64,
+            ));
+        }
+        let end = egui::pos2(150.0, start.y);
+        step.or(frame(
+            vec![touch(egui::TouchPhase::End, end), button(false, end)],
+            0.4,
+        ))
+    }
+
+    #[test]
+    fn detect_swipes() {
+        assert_eq!(swipe(false), Some(1));
+        // Dragging a widget horizontally does not change the page
+        assert_eq!(swipe(true), None);
+    }
+}
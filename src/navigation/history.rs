@@ -0,0 +1,57 @@
+/// Back/forward history of visited pages.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History<T> {
+    back: Vec<T>,
+    forward: Vec<T>,
+}
+
+impl<T> History<T> {
+    /// Record a visit to a new location, which invalidates the forward history.
+    pub fn push(&mut self, previous: T) {
+        self.back.push(previous);
+        self.forward.clear();
+    }
+
+    /// Step back in history, returning the location to go to.
+    pub fn back(&mut self, current: T) -> Option<T> {
+        let target = self.back.pop()?;
+        self.forward.push(current);
+        Some(target)
+    }
+
+    /// Step forward in history, returning the location to go to.
+    pub fn forward(&mut self, current: T) -> Option<T> {
+        let target = self.forward.pop()?;
+        self.back.push(current);
+        Some(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn back_and_forward() {
+        let mut history = History::default();
+        history.push(1);
+        history.push(2);
+
+        assert_eq!(history.back(3), Some(2));
+        assert_eq!(history.back(2), Some(1));
+        assert_eq!(history.back(1), None);
+
+        assert_eq!(history.forward(1), Some(2));
+        assert_eq!(history.forward(2), Some(3));
+        assert_eq!(history.forward(3), None);
+    }
+
+    #[test]
+    fn push_clears_forward() {
+        let mut history = History::default();
+        history.push(1);
+        assert_eq!(history.back(2), Some(1));
+        history.push(1);
+        assert_eq!(history.forward(1), None);
+    }
+}
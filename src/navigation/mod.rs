@@ -0,0 +1,525 @@
+//! Navigation between pages for both the native and web builds.
+//!
+//! Widgets request navigation via [`open_route`], which is applied by [`Navigator::update`] at
+//! the beginning of the next frame. The native app keeps its own back/forward history,
+//! whereas the web app keeps the URL path in sync via the History API and defers to the
+//! browser history.
+
+pub use focus::highlight_if_targeted;
+#[cfg(feature = "bibliography")]
+pub use query::{decode_query, encode_query};
+pub use redirects::{RedirectTarget, Redirects};
+pub use route::{Route, Target};
+pub use scroll::{remember_scroll_offset, scroll_area};
+#[cfg(feature = "page-research")]
+pub use tabs::tab_strip;
+pub use transition::transition_style;
+
+mod focus;
+mod gestures;
+#[cfg(not(target_arch = "wasm32"))]
+mod history;
+#[cfg(feature = "bibliography")]
+mod query;
+mod redirects;
+mod route;
+mod scroll;
+#[cfg(feature = "page-research")]
+mod tabs;
+mod transition;
+#[cfg(target_arch = "wasm32")]
+mod web;
+
+use std::borrow::Cow;
+
+// The generated registry does not name the type if there are no short links
+#[allow(unused_imports)]
+use redirects::Redirect;
+
+use crate::page::Page;
+
+include!(concat!(env!("OUT_DIR"), "/redirects.rs"));
+
+/// Prepare the navigation for the app.
+///
+/// On the web, the app is repainted on browser history changes. Natively, the route passed as
+/// the first command-line argument is opened, e.g. `research/orsula2022learning`.
+pub fn init(ctx: &egui::Context) {
+    #[cfg(target_arch = "wasm32")]
+    web::install_popstate_listener(ctx);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(path) = std::env::args().nth(1) {
+        open_path(ctx, path.trim_start_matches('#'), true);
+    }
+}
+
+/// Registry of short links from `content/redirects.yaml`.
+pub fn redirects() -> &'static Redirects {
+    &REDIRECTS
+}
+
+/// Current date used to determine whether short links have expired.
+pub fn today() -> chrono::NaiveDate {
+    chrono::Utc::now().date_naive()
+}
+
+/// Destination of a requested path.
+enum Resolution {
+    Route(Route),
+    Url(String),
+    NotFound,
+}
+
+/// Resolve the path via the short links first and only then via the routes.
+fn resolve(path: &str) -> Resolution {
+    if let Some(redirect) = redirects().get(path, today()) {
+        match &redirect.target {
+            RedirectTarget::Url(url) => Resolution::Url(absolute_url(url)),
+            RedirectTarget::Route(route) => {
+                Route::parse(route).map_or(Resolution::NotFound, Resolution::Route)
+            }
+        }
+    } else {
+        Route::parse(path).map_or(Resolution::NotFound, Resolution::Route)
+    }
+}
+
+/// Open the given route, either in place or in a new tab (web only).
+pub fn open_route(ctx: &egui::Context, route: impl Into<Route>, same_tab: bool) {
+    let route = route.into();
+    if let Some(url) = route.page.redirect_page() {
+        let url = absolute_url(url);
+        ctx.open_url(if same_tab {
+            egui::OpenUrl::same_tab(url)
+        } else {
+            egui::OpenUrl::new_tab(url)
+        });
+    } else if same_tab || cfg!(not(target_arch = "wasm32")) {
+        // The native app has no tabs, so the route is always opened in place
+        submit_request(ctx, Request::Open(route));
+    } else {
+        ctx.open_url(egui::OpenUrl::new_tab(route_url(&route)));
+    }
+}
+
+/// Open the given path after resolving it via the short links and routes.
+pub fn open_path(ctx: &egui::Context, path: &str, same_tab: bool) {
+    match resolve(path) {
+        Resolution::Route(route) => open_route(ctx, route, same_tab),
+        Resolution::Url(url) => ctx.open_url(if same_tab {
+            egui::OpenUrl::same_tab(url)
+        } else {
+            egui::OpenUrl::new_tab(url)
+        }),
+        Resolution::NotFound => submit_request(ctx, Request::NotFound(path.to_owned())),
+    }
+}
+
+/// Replace the query of the current route, e.g. after the visitor changed a filter.
+///
+/// Unlike opening a route, this neither adds an entry to the history nor scrolls.
+#[cfg(feature = "bibliography")]
+pub fn replace_query(ctx: &egui::Context, query: Option<String>) {
+    submit_request(ctx, Request::ReplaceQuery(query));
+}
+
+/// Replace the current route with the given one, e.g. after closing the details of an entry.
+///
+/// Unlike opening a route, this does not add an entry to the history.
+#[cfg(feature = "bibliography")]
+pub fn replace_route(ctx: &egui::Context, route: Route) {
+    submit_request(ctx, Request::Replace(route));
+}
+
+/// Show that the given path was not found, e.g. for an entry that is missing from the content
+/// that was loaded at runtime.
+#[cfg(feature = "bibliography")]
+pub fn open_not_found(ctx: &egui::Context, path: String) {
+    submit_request(ctx, Request::NotFound(path));
+}
+
+/// URL of the given route relative to the root of the website.
+pub fn route_url(route: &Route) -> String {
+    format!("/{route}")
+}
+
+/// Absolute URL of the given route that can be shared outside of the app.
+pub fn permalink(route: &Route) -> String {
+    format!("{}{}", env!("CARGO_PKG_HOMEPAGE"), route_url(route))
+}
+
+/// Resolve URLs relative to the root of the website for the native app.
+fn absolute_url(url: &str) -> String {
+    if cfg!(not(target_arch = "wasm32")) && url.starts_with('/') {
+        format!("{}{url}", env!("CARGO_PKG_HOMEPAGE"))
+    } else {
+        url.to_owned()
+    }
+}
+
+fn route_id() -> egui::Id {
+    egui::Id::new("navigation_route")
+}
+
+/// Route that is shown in the current frame.
+#[cfg(feature = "page-research")]
+pub fn visible_route(ctx: &egui::Context) -> Route {
+    ctx.data(|data| data.get_temp(route_id()))
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone)]
+enum Request {
+    Open(Route),
+    #[cfg(feature = "bibliography")]
+    Replace(Route),
+    #[cfg(feature = "bibliography")]
+    ReplaceQuery(Option<String>),
+    NotFound(String),
+}
+
+fn request_id() -> egui::Id {
+    egui::Id::new("navigation_request")
+}
+
+fn submit_request(ctx: &egui::Context, request: Request) {
+    ctx.data_mut(|data| data.insert_temp(request_id(), request));
+    ctx.request_repaint();
+}
+
+fn take_request(ctx: &egui::Context) -> Option<Request> {
+    ctx.data_mut(|data| {
+        let request = data.get_temp::<Request>(request_id());
+        data.remove::<Request>(request_id());
+        request
+    })
+}
+
+#[derive(Debug, Default)]
+pub struct Navigator {
+    current_route: Route,
+    /// Path that was requested but does not match any route
+    unknown_path: Option<String>,
+    #[cfg(not(target_arch = "wasm32"))]
+    history: history::History<Route>,
+    /// Last scroll offset of each visited route
+    scroll_offsets: rustc_hash::FxHashMap<String, f32>,
+    swipe: gestures::SwipeDetector,
+    transition: Option<transition::Transition>,
+    /// Path and hash of the URL that the navigator last followed (web only)
+    #[cfg(any(test, target_arch = "wasm32"))]
+    synced_location: Option<(String, String)>,
+}
+
+impl Navigator {
+    /// Navigator that restores the given scroll offsets of the previous visit.
+    pub fn with_scroll_offsets(scroll_offsets: rustc_hash::FxHashMap<String, f32>) -> Self {
+        Self {
+            scroll_offsets,
+            ..Self::default()
+        }
+    }
+
+    pub fn scroll_offsets(&self) -> &rustc_hash::FxHashMap<String, f32> {
+        &self.scroll_offsets
+    }
+
+    /// Route that is currently shown, or `None` if an unknown path was requested.
+    pub fn current_route(&self) -> Option<&Route> {
+        self.unknown_path.is_none().then_some(&self.current_route)
+    }
+
+    /// Page that is currently shown, or `None` if an unknown path was requested.
+    pub fn current_page(&self) -> Option<Page> {
+        self.unknown_path
+            .is_none()
+            .then_some(self.current_route.page)
+    }
+
+    pub fn unknown_path(&self) -> Option<&str> {
+        self.unknown_path.as_deref()
+    }
+
+    /// Page that should be shown in the current frame, which differs from the current page
+    /// during the first half of a transition.
+    pub fn visible_page(&self) -> Option<Page> {
+        self.unknown_path
+            .is_none()
+            .then(|| self.visible_route().page)
+    }
+
+    fn visible_route(&self) -> &Route {
+        match &self.transition {
+            Some(transition) if transition.leaving => &transition.from,
+            _ => &self.current_route,
+        }
+    }
+
+    pub fn update(&mut self, ctx: &egui::Context) {
+        // Follow the URL if it was changed externally (e.g. via browser history)
+        #[cfg(target_arch = "wasm32")]
+        self.sync_with_location(ctx);
+
+        // Back/forward via mouse side buttons or Alt+Left/Right
+        let (back, forward) = ctx.input_mut(|i| {
+            (
+                i.pointer.button_pressed(egui::PointerButton::Extra1)
+                    | i.consume_key(egui::Modifiers::ALT, egui::Key::ArrowLeft),
+                i.pointer.button_pressed(egui::PointerButton::Extra2)
+                    | i.consume_key(egui::Modifiers::ALT, egui::Key::ArrowRight),
+            )
+        });
+        if back {
+            self.back(ctx);
+        } else if forward {
+            self.forward(ctx);
+        }
+
+        // Previous/next page via arrow keys or horizontal swipes
+        if let Some(step) = gestures::page_step(ctx, &mut self.swipe) {
+            self.step(ctx, step);
+        }
+
+        // Apply the pending navigation request
+        match take_request(ctx) {
+            Some(Request::Open(route)) => self.navigate(ctx, route),
+            #[cfg(feature = "bibliography")]
+            Some(Request::Replace(route)) => self.replace(ctx, route),
+            #[cfg(feature = "bibliography")]
+            Some(Request::ReplaceQuery(query)) => self.replace_query(query),
+            Some(Request::NotFound(path)) => self.unknown_path = Some(path),
+            None => {}
+        }
+
+        // Advance the transition between pages
+        if let Some(transition) = &mut self.transition {
+            if !transition.update(ctx) {
+                self.transition = None;
+            }
+        }
+
+        // Restore the scroll position when entering a route and remember it afterwards
+        self.sync_scroll_offset(ctx);
+
+        // Share the visible route with the pages (e.g. to select their tab)
+        let route = self.visible_route().clone();
+        ctx.data_mut(|data| data.insert_temp(route_id(), route));
+    }
+
+    fn sync_scroll_offset(&mut self, ctx: &egui::Context) {
+        if self.unknown_path.is_some() {
+            scroll::ScrollState::clear(ctx);
+            return;
+        }
+
+        // The query only changes the state of the view, so it shares the scroll offset
+        let route = self.visible_route();
+        let key = Route {
+            query: None,
+            ..route.clone()
+        }
+        .to_string();
+        match scroll::ScrollState::load(ctx) {
+            Some(scroll::ScrollState {
+                key: previous_key,
+                offset,
+                ..
+            }) if previous_key == key => {
+                if let Some(offset) = offset {
+                    self.scroll_offsets.insert(key, offset);
+                }
+            }
+            _ => {
+                // Links to a specific entry scroll to it instead
+                let offset = if route.target.is_none() {
+                    self.scroll_offsets.get(&key).copied()
+                } else {
+                    None
+                };
+                scroll::ScrollState::restoring(key, offset).store(ctx);
+            }
+        }
+    }
+
+    fn navigate(&mut self, ctx: &egui::Context, route: Route) {
+        if route == self.current_route && self.unknown_path.is_none() {
+            return;
+        }
+
+        // On the web, the browser records the change of the URL in its history
+        #[cfg(target_arch = "wasm32")]
+        web::push_url(&route_url(&route));
+        #[cfg(not(target_arch = "wasm32"))]
+        self.history.push(self.current_route.clone());
+
+        self.set_current_route(ctx, route);
+    }
+
+    #[cfg(feature = "bibliography")]
+    fn replace(&mut self, ctx: &egui::Context, route: Route) {
+        if route == self.current_route && self.unknown_path.is_none() {
+            return;
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        web::replace_url(&route_url(&route));
+
+        self.set_current_route(ctx, route);
+    }
+
+    #[cfg(feature = "bibliography")]
+    fn replace_query(&mut self, query: Option<String>) {
+        if self.unknown_path.is_some() || self.current_route.query == query {
+            return;
+        }
+        // The view no longer points to a specific entry
+        self.current_route.target = None;
+        self.current_route.query = query;
+
+        #[cfg(target_arch = "wasm32")]
+        web::replace_url(&route_url(&self.current_route));
+    }
+
+    /// Navigate to the page that is `step` pages away in the order of the navigation panel.
+    fn step(&mut self, ctx: &egui::Context, step: isize) {
+        if self.unknown_path.is_some() {
+            return;
+        }
+
+        // Redirects are skipped because they would leave the app
+        let pages = crate::page::registry()
+            .iter()
+            .copied()
+            .filter(|page| page.redirect_page().is_none())
+            .collect::<Vec<_>>();
+        if let Some(&page) = pages
+            .iter()
+            .position(|&page| page == self.current_route.page)
+            .and_then(|index| index.checked_add_signed(step))
+            .and_then(|index| pages.get(index))
+        {
+            self.navigate(ctx, page.into());
+        }
+    }
+
+    fn set_current_route(&mut self, ctx: &egui::Context, route: Route) {
+        if let Some(target) = &route.target {
+            focus::set(ctx, target.clone());
+        }
+        if route.page != self.current_route.page && self.unknown_path.is_none() {
+            self.transition = transition::Transition::start(ctx, &self.current_route, &route);
+        }
+        self.current_route = route;
+        self.unknown_path = None;
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn back(&mut self, _ctx: &egui::Context) {
+        web::back();
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn forward(&mut self, _ctx: &egui::Context) {
+        web::forward();
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn back(&mut self, ctx: &egui::Context) {
+        if let Some(route) = self.history.back(self.current_route.clone()) {
+            self.set_current_route(ctx, route);
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn forward(&mut self, ctx: &egui::Context) {
+        if let Some(route) = self.history.forward(self.current_route.clone()) {
+            self.set_current_route(ctx, route);
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn sync_with_location(&mut self, ctx: &egui::Context) {
+        if let Some((path, hash)) = web::location() {
+            self.sync_with(ctx, path, hash);
+        }
+    }
+
+    /// Follow the URL with the given path and hash if it changed since the last call.
+    ///
+    /// Unchanged URLs are ignored, so that a path that turned out to be unknown only after it
+    /// was opened (e.g. an entry that is missing from the fetched content) stays not found.
+    #[cfg(any(test, target_arch = "wasm32"))]
+    // The tests run natively, where there is no URL to rewrite
+    #[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+    fn sync_with(&mut self, ctx: &egui::Context, path: String, hash: String) {
+        let location = (path, hash);
+        if self.synced_location.as_ref() == Some(&location) {
+            return;
+        }
+        self.synced_location = Some(location.clone());
+        let (path, hash) = location;
+
+        // Hash-based URLs (e.g. `/#research`) that were shared in the past are still supported
+        let (path, is_legacy_url) = match hash.strip_prefix('#') {
+            Some(legacy_path) if !legacy_path.is_empty() => (legacy_path.to_owned(), true),
+            _ => (path, false),
+        };
+
+        match resolve(&path) {
+            Resolution::Url(url) => {
+                // If a redirect was requested, redirect to it
+                ctx.open_url(egui::OpenUrl::same_tab(url));
+            }
+            Resolution::Route(route) => {
+                // Rewrite legacy URLs and short links to the canonical path of the route
+                let path = path.trim_matches('/');
+                #[cfg(target_arch = "wasm32")]
+                if is_legacy_url || (!path.is_empty() && path != route.to_string()) {
+                    web::replace_url(&route_url(&route));
+                }
+
+                // If a known route was requested, update the current route
+                if route != self.current_route || self.unknown_path.is_some() {
+                    self.set_current_route(ctx, route);
+                }
+            }
+            Resolution::NotFound if self.unknown_path.as_ref() != Some(&path) => {
+                // If an unknown route was requested, let the visitor know
+                self.unknown_path = Some(path);
+            }
+            Resolution::NotFound => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_not_found_until_location_changes() {
+        let ctx = egui::Context::default();
+        let mut navigator = Navigator::default();
+        let mut run = |update: &mut dyn FnMut(&mut Navigator, &egui::Context)| {
+            let _ = ctx.run(egui::RawInput::default(), |ctx| update(&mut navigator, ctx));
+        };
+
+        run(&mut |navigator, ctx| {
+            navigator.sync_with(ctx, "/about".to_owned(), String::new());
+            submit_request(ctx, Request::NotFound("about/unknown".to_owned()));
+            navigator.update(ctx);
+        });
+        run(&mut |navigator, ctx| {
+            navigator.sync_with(ctx, "/about".to_owned(), String::new());
+            navigator.update(ctx);
+            assert_eq!(navigator.unknown_path(), Some("about/unknown"));
+        });
+
+        run(&mut |navigator, ctx| {
+            navigator.sync_with(ctx, "/".to_owned(), String::new());
+            assert_eq!(navigator.unknown_path(), None);
+            assert_eq!(navigator.current_route(), Some(&Route::default()));
+        });
+    }
+}
@@ -0,0 +1,97 @@
+//! Query of a route, which keeps the state of a view in the URL so that it can be shared, e.g.
+//! `research/publications?q=moon&type=journal`.
+
+/// Encode the parameters with a value as a query, or `None` if there are none.
+pub fn encode_query<'a>(params: impl IntoIterator<Item = (&'a str, String)>) -> Option<String> {
+    let query = params
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(name, value)| format!("{}={}", encode(name), encode(&value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    (!query.is_empty()).then_some(query)
+}
+
+/// Decode the parameters of a query in their order.
+pub fn decode_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+            (decode(name), decode(value))
+        })
+        .collect()
+}
+
+/// Percent-encode everything but the unreserved characters, with spaces as `+`.
+fn encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(byte).to_string()
+            }
+            b' ' => "+".to_owned(),
+            byte => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Decode a percent-encoded text, keeping malformed escapes as they are.
+fn decode(text: &str) -> String {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut input = text.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let escape = input.clone().take(2).collect::<Vec<_>>();
+                match std::str::from_utf8(&escape)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    Some(decoded) if escape.len() == 2 => {
+                        bytes.push(decoded);
+                        input.nth(1);
+                    }
+                    _ => bytes.push(b'%'),
+                }
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_roundtrip() {
+        let query = encode_query([
+            ("q", "grasp moon".to_owned()),
+            ("author", "Simon Bøgh".to_owned()),
+            ("type", String::new()),
+        ]);
+        assert_eq!(
+            query.as_deref(),
+            Some("q=grasp+moon&author=Simon+B%C3%B8gh")
+        );
+        assert_eq!(
+            decode_query(&query.unwrap()),
+            [
+                ("q".to_owned(), "grasp moon".to_owned()),
+                ("author".to_owned(), "Simon Bøgh".to_owned()),
+            ]
+        );
+        assert_eq!(encode_query([("q", String::new())]), None);
+        assert_eq!(
+            decode_query("q=100%&&from"),
+            [
+                ("q".to_owned(), "100%".to_owned()),
+                ("from".to_owned(), String::new()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Short links, whose schema is shared with the build script that compiles
+//! `content/redirects.yaml` into static data.
+
+use std::borrow::Cow;
+
+/// Registry of short links that redirect to external URLs or internal routes.
+pub struct Redirects {
+    entries: Cow<'static, [Redirect]>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct Redirect {
+    pub slug: Cow<'static, str>,
+    #[serde(flatten)]
+    pub target: RedirectTarget,
+    /// Last day on which the redirect is active
+    #[serde(default)]
+    pub expires: Option<chrono::NaiveDate>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedirectTarget {
+    /// External URL or a file hosted alongside the app
+    Url(Cow<'static, str>),
+    /// Internal route, e.g. `research/orsula2022learning`
+    Route(Cow<'static, str>),
+}
+
+impl std::fmt::Display for RedirectTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Url(url) => write!(f, "{url}"),
+            Self::Route(route) => write!(f, "/{route}"),
+        }
+    }
+}
+
+impl Redirect {
+    pub fn is_expired(&self, today: chrono::NaiveDate) -> bool {
+        self.expires.is_some_and(|expires| expires < today)
+    }
+}
+
+impl Redirects {
+    pub fn iter(&self) -> impl Iterator<Item = &Redirect> {
+        self.entries.iter()
+    }
+
+    pub const fn new(entries: &'static [Redirect]) -> Self {
+        Self {
+            entries: Cow::Borrowed(entries),
+        }
+    }
+
+    /// Get the active redirect registered for the slug.
+    pub fn get(&self, slug: &str, today: chrono::NaiveDate) -> Option<&Redirect> {
+        let slug = slug.trim_matches('/');
+        self.iter().find(|redirect| {
+            redirect.slug.eq_ignore_ascii_case(slug) && !redirect.is_expired(today)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_redirects() {
+        const REDIRECTS: &str = indoc::indoc! {"
+            - slug: srb
+              url: https://github.com/AndrejOrsula/space_robotics_bench
+            - slug: icra25-slides
+              route: research/orsula2022learning
+              expires: 2025-06-30
+        "};
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+
+        let redirects = Redirects {
+            entries: Cow::Owned(crate::content::parse::yaml(REDIRECTS).unwrap()),
+        };
+        assert_eq!(
+            redirects.get("SRB", today).unwrap().target,
+            RedirectTarget::Url("https://github.com/AndrejOrsula/space_robotics_bench".into())
+        );
+        assert_eq!(
+            redirects.get("/icra25-slides", today).unwrap().target,
+            RedirectTarget::Route("research/orsula2022learning".into())
+        );
+        assert!(redirects
+            .get("icra25-slides", today.succ_opt().unwrap())
+            .is_none());
+        assert!(redirects.get("unknown", today).is_none());
+    }
+
+    #[test]
+    fn generated_redirects() {
+        assert_eq!(
+            crate::content::parse::yaml::<Vec<Redirect>>(crate::macros::include_content_str!(
+                "redirects.yaml"
+            ))
+            .unwrap(),
+            crate::navigation::redirects()
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn registered_routes_exist() {
+        for redirect in crate::navigation::redirects().iter() {
+            if let RedirectTarget::Route(route) = &redirect.target {
+                // Pages that are excluded by their cargo feature cannot be checked
+                if route
+                    .split('/')
+                    .next()
+                    .is_some_and(|slug| crate::page::EXCLUDED_PAGES.contains(&slug))
+                {
+                    continue;
+                }
+                assert!(
+                    crate::navigation::Route::parse(route).is_some(),
+                    "Short link `{}` points to an unknown route `{route}`",
+                    redirect.slug
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,248 @@
+#[cfg(feature = "page-research")]
+use crate::page::Tab;
+#[cfg(feature = "bibliography")]
+use crate::page::RESEARCH;
+use crate::page::{Page, ABOUT};
+
+/// Location within the app, e.g. `research/publications?q=moon` or `about/updates/2025-05-17`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Route {
+    pub page: Page,
+    /// Slug of the selected tab, or `None` for the first tab of the page
+    pub tab: Option<&'static str>,
+    pub target: Option<Target>,
+    /// State of the view that is kept in the URL (see [`super::encode_query`]), without the
+    /// leading `?`
+    pub query: Option<String>,
+}
+
+/// Specific entry on a page that a route points to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// Bibliography entry identified by its key
+    #[cfg(feature = "bibliography")]
+    Publication(String),
+    /// Update identified by its date
+    Update(chrono::NaiveDate),
+}
+
+impl From<Page> for Route {
+    fn from(page: Page) -> Self {
+        Self {
+            page,
+            tab: None,
+            target: None,
+            query: None,
+        }
+    }
+}
+
+impl From<Target> for Route {
+    fn from(target: Target) -> Self {
+        let (page, tab) = match target {
+            #[cfg(feature = "bibliography")]
+            Target::Publication(_) => (RESEARCH, Some("publications")),
+            Target::Update(_) => (ABOUT, None),
+        };
+        Self {
+            page,
+            tab,
+            target: Some(target),
+            query: None,
+        }
+    }
+}
+
+impl std::fmt::Display for Route {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.page.slug())?;
+        // The tab of a target is implied, which keeps the links to entries short
+        match (&self.target, self.tab) {
+            #[cfg(feature = "bibliography")]
+            (Some(Target::Publication(key)), _) => write!(f, "/{key}"),
+            (Some(Target::Update(date)), _) => write!(f, "/updates/{date}"),
+            (None, Some(tab)) => write!(f, "/{tab}"),
+            (None, None) => Ok(()),
+        }?;
+        match &self.query {
+            Some(query) => write!(f, "?{query}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Route {
+    /// Tab of the page that the route points to, or `None` if the page has no tabs.
+    #[cfg(feature = "page-research")]
+    pub fn tab(&self) -> Option<Tab> {
+        let tabs = self.page.tabs();
+        self.tab
+            .and_then(|slug| tabs.iter().find(|tab| tab.slug == slug))
+            .or(tabs.first())
+            .copied()
+    }
+
+    /// Parse a route from its path with an optional query, e.g. `research/orsula2022learning`.
+    ///
+    /// Returns `None` if the path does not match any enabled page or entry.
+    pub fn parse(path: &str) -> Option<Self> {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let query = (!query.is_empty()).then(|| query.to_owned());
+        let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+
+        let page = match segments.next() {
+            Some(slug) => Page::find(slug)?,
+            None => {
+                return Some(Self {
+                    query,
+                    ..Self::default()
+                })
+            }
+        };
+
+        // The tab is optional and defaults to the first tab of the page
+        let mut segment = segments.next();
+        let tab = segment.and_then(|slug| {
+            page.tabs()
+                .iter()
+                .find(|tab| tab.slug.eq_ignore_ascii_case(slug))
+        });
+        if tab.is_some() {
+            segment = segments.next();
+        }
+
+        let target = match (page, tab.map(|tab| tab.slug), segment, segments.next()) {
+            (_, _, None, None) => None,
+            #[cfg(feature = "bibliography")]
+            // Only the keys of the bibliography can be linked to, so that typos are reported. The
+            // fetched bibliography may contain newer entries, so it reports unknown keys instead.
+            (page, None | Some("publications"), Some(key), None) if page == RESEARCH => {
+                if cfg!(feature = "remote-content") {
+                    Some(Target::Publication(key.to_owned()))
+                } else {
+                    Some(Target::Publication(
+                        crate::content::PUBLICATIONS
+                            .iter()
+                            .find(|publication| publication.key == key)?
+                            .key
+                            .to_string(),
+                    ))
+                }
+            }
+            // Only the dates of updates can be linked to, so that typos are reported
+            (page, None, Some("updates"), Some(date)) if page == ABOUT => Some(Target::Update(
+                chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                    .ok()
+                    .filter(|date| {
+                        crate::content::UPDATES
+                            .iter()
+                            .any(|update| update.date == *date)
+                    })?,
+            )),
+            _ => return None,
+        };
+
+        if segments.next().is_some() {
+            return None;
+        }
+
+        if let Some(target) = target {
+            return Some(Self {
+                query,
+                ..target.into()
+            });
+        }
+        Some(Self {
+            page,
+            tab: tab
+                .filter(|&tab| Some(tab) != page.tabs().first())
+                .map(|tab| tab.slug),
+            target: None,
+            query,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_page() {
+        assert_eq!(Route::parse(""), Some(Route::default()));
+        assert_eq!(Route::parse("About/"), Some(ABOUT.into()));
+        #[cfg(feature = "bibliography")]
+        assert_eq!(Route::parse("research"), Some(RESEARCH.into()));
+        assert_eq!(Route::parse("unknown"), None);
+    }
+
+    #[cfg(feature = "bibliography")]
+    #[test]
+    fn parse_tab() {
+        assert_eq!(Route::parse("research/overview"), Some(RESEARCH.into()));
+        assert_eq!(
+            Route::parse("research/Talks"),
+            Some(Route {
+                tab: Some("talks"),
+                ..RESEARCH.into()
+            })
+        );
+        assert_eq!(
+            Route::parse("research/publications/orsula2022learning"),
+            Route::parse("research/orsula2022learning")
+        );
+        assert_eq!(Route::parse("research/talks/orsula2022learning"), None);
+        assert_eq!(
+            Route::parse("research/talks?q=moon"),
+            Some(Route {
+                tab: Some("talks"),
+                query: Some("q=moon".to_owned()),
+                ..RESEARCH.into()
+            })
+        );
+    }
+
+    #[test]
+    fn parse_target() {
+        #[cfg(feature = "bibliography")]
+        assert_eq!(
+            Route::parse("research/orsula2022learning"),
+            Some(Target::Publication("orsula2022learning".to_owned()).into())
+        );
+        assert_eq!(
+            Route::parse("about/updates/2025-05-17"),
+            Some(Target::Update(chrono::NaiveDate::from_ymd_opt(2025, 5, 17).unwrap()).into())
+        );
+        assert_eq!(Route::parse("about/updates/2025-13-01"), None);
+        assert_eq!(Route::parse("about/updates/2099-01-01"), None);
+        assert_eq!(Route::parse("teaching/rmins"), None);
+        assert_eq!(Route::parse("research/orsula2022learning/extra"), None);
+        #[cfg(all(feature = "bibliography", not(feature = "remote-content")))]
+        assert_eq!(Route::parse("research/orsula2022lerning"), None);
+        #[cfg(all(feature = "bibliography", feature = "remote-content"))]
+        assert_eq!(
+            Route::parse("research/orsula2099unreleased"),
+            Some(Target::Publication("orsula2099unreleased".to_owned()).into())
+        );
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        for path in [
+            "about",
+            "about/updates/2025-05-17",
+            #[cfg(feature = "page-research")]
+            "research",
+            #[cfg(feature = "bibliography")]
+            "research/publications",
+            #[cfg(feature = "bibliography")]
+            "research/talks",
+            #[cfg(feature = "bibliography")]
+            "research/orsula2022learning",
+            #[cfg(feature = "bibliography")]
+            "research/publications?q=moon&type=journal",
+        ] {
+            assert_eq!(Route::parse(path).unwrap().to_string(), path);
+        }
+    }
+}
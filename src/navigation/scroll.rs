@@ -0,0 +1,79 @@
+/// Maximum number of frames over which a stored scroll offset is restored, which allows the
+/// content (e.g. images) to grow to its full size.
+const MAX_RESTORE_FRAMES: u8 = 10;
+
+/// Scroll position of the current route that is shared with the scroll area of its page.
+#[derive(Debug, Clone, Default)]
+pub(super) struct ScrollState {
+    /// Key under which the scroll offset of the current route is stored
+    pub key: String,
+    /// Offset that should be restored, along with the remaining number of attempts
+    pub restore: Option<(f32, u8)>,
+    /// Offset of the scroll area in the last frame
+    pub offset: Option<f32>,
+    /// Widget that is dragged while the visitor drags the page itself (e.g. to scroll it)
+    pub drag_id: Option<egui::Id>,
+}
+
+fn state_id() -> egui::Id {
+    egui::Id::new("navigation_scroll")
+}
+
+impl ScrollState {
+    pub fn restoring(key: String, offset: Option<f32>) -> Self {
+        Self {
+            key,
+            restore: offset.map(|offset| (offset, MAX_RESTORE_FRAMES)),
+            offset: None,
+            drag_id: None,
+        }
+    }
+
+    pub fn load(ctx: &egui::Context) -> Option<Self> {
+        ctx.data(|data| data.get_temp(state_id()))
+    }
+
+    pub fn store(self, ctx: &egui::Context) {
+        ctx.data_mut(|data| data.insert_temp(state_id(), self));
+    }
+
+    pub fn clear(ctx: &egui::Context) {
+        ctx.data_mut(|data| data.remove::<Self>(state_id()));
+    }
+}
+
+/// Vertical scroll area for the current route that restores its previous scroll position.
+pub fn scroll_area(ctx: &egui::Context) -> egui::ScrollArea {
+    let scroll_area = egui::ScrollArea::vertical();
+    let Some(state) = ScrollState::load(ctx) else {
+        return scroll_area;
+    };
+
+    let scroll_area = scroll_area.id_salt(&state.key);
+    if let Some((offset, _)) = state.restore {
+        ctx.request_repaint();
+        scroll_area.vertical_scroll_offset(offset)
+    } else {
+        scroll_area
+    }
+}
+
+/// Remember the scroll offset of the current route after showing its scroll area.
+pub fn remember_scroll_offset<R>(
+    ctx: &egui::Context,
+    output: &egui::scroll_area::ScrollAreaOutput<R>,
+) {
+    let Some(mut state) = ScrollState::load(ctx) else {
+        return;
+    };
+    let offset = output.state.offset.y;
+    // egui drags the content of scroll areas via a widget with this id
+    state.drag_id = Some(output.id.with("area"));
+
+    if let Some((target, attempts)) = state.restore {
+        state.restore =
+            ((target - offset).abs() > 1.0 && attempts > 1).then_some((target, attempts - 1));
+    }
+    state.offset = Some(offset);
+    state.store(ctx);
+}
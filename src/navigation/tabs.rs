@@ -0,0 +1,59 @@
+use crate::page::{Page, Tab};
+
+/// Show the tabs of the page as a centered strip and return the selected tab.
+///
+/// Each tab has its own route, so selecting a tab behaves like any other navigation.
+pub fn tab_strip(ui: &mut egui::Ui, page: Page) -> Option<Tab> {
+    let route = super::visible_route(ui.ctx());
+    let selected = if route.page == page {
+        route.tab()
+    } else {
+        super::Route::from(page).tab()
+    };
+    let tabs = page.tabs();
+    if tabs.len() < 2 {
+        return selected;
+    }
+
+    ui.horizontal(|ui| {
+        let text_style = egui::TextStyle::Name("navigation_panel_display".into());
+        ui.style_mut().override_text_style = Some(text_style.clone());
+        ui.spacing_mut().item_spacing.x = 16.0;
+
+        // Center the strip by measuring the width of all tabs in advance
+        let font_id = text_style.resolve(ui.style());
+        let width = tabs
+            .iter()
+            .map(|tab| {
+                ui.fonts(|fonts| {
+                    fonts
+                        .layout_no_wrap(
+                            tab.title.to_owned(),
+                            font_id.clone(),
+                            egui::Color32::PLACEHOLDER,
+                        )
+                        .size()
+                        .x
+                }) + 2.0 * ui.spacing().button_padding.x
+            })
+            .sum::<f32>()
+            + ui.spacing().item_spacing.x * (tabs.len() - 1) as f32;
+        ui.add_space(((ui.available_width() - width) / 2.0).max(0.0));
+
+        for &tab in tabs {
+            let response = ui.selectable_label(Some(tab) == selected, tab.title);
+            let route = super::Route {
+                tab: (Some(&tab) != tabs.first()).then_some(tab.slug),
+                ..page.into()
+            };
+            if response.clicked() {
+                super::open_route(ui.ctx(), route, true);
+            } else if response.middle_clicked() {
+                super::open_route(ui.ctx(), route, false);
+            }
+        }
+    });
+    ui.add_space(ui.spacing().item_spacing.y);
+
+    selected
+}
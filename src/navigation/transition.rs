@@ -0,0 +1,105 @@
+use super::Route;
+
+/// Duration of the transition between two pages in seconds.
+const DURATION: f64 = 0.3;
+/// Horizontal distance by which the pages slide during the transition.
+const SLIDE_DISTANCE: f32 = 48.0;
+
+/// Animated transition from one page to another.
+///
+/// The previous page slides out and fades away during the first half of the transition,
+/// after which the next page slides in from the opposite side.
+#[derive(Debug, Clone)]
+pub(super) struct Transition {
+    pub from: Route,
+    /// Whether the previous page is still shown
+    pub leaving: bool,
+    /// Direction in which the pages slide (-1 for the previous page, +1 for the next page)
+    direction: f32,
+    start_time: f64,
+}
+
+/// Opacity and horizontal offset of the page content during a transition.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TransitionStyle {
+    pub opacity: f32,
+    pub offset: f32,
+}
+
+fn style_id() -> egui::Id {
+    egui::Id::new("navigation_transition")
+}
+
+impl Transition {
+    /// Start a transition between two pages unless the visitor prefers reduced motion.
+    pub fn start(ctx: &egui::Context, from: &Route, to: &Route) -> Option<Self> {
+        if prefers_reduced_motion(ctx) {
+            return None;
+        }
+
+        let position = |route: &Route| {
+            crate::page::registry()
+                .iter()
+                .position(|&x| x == route.page)
+        };
+        let direction = if position(to) >= position(from) {
+            1.0
+        } else {
+            -1.0
+        };
+        Some(Self {
+            from: from.clone(),
+            leaving: true,
+            direction,
+            start_time: ctx.input(|i| i.time),
+        })
+    }
+
+    /// Advance the transition, returning `false` once it is over.
+    pub fn update(&mut self, ctx: &egui::Context) -> bool {
+        let progress = ((ctx.input(|i| i.time) - self.start_time) / DURATION) as f32;
+        if progress >= 1.0 {
+            ctx.data_mut(|data| data.remove::<TransitionStyle>(style_id()));
+            return false;
+        }
+
+        self.leaving = progress < 0.5;
+        let style = if self.leaving {
+            TransitionStyle {
+                opacity: 1.0 - 2.0 * progress,
+                offset: -self.direction * SLIDE_DISTANCE * 2.0 * progress,
+            }
+        } else {
+            TransitionStyle {
+                opacity: 2.0 * progress - 1.0,
+                offset: self.direction * SLIDE_DISTANCE * 2.0 * (1.0 - progress),
+            }
+        };
+        ctx.data_mut(|data| data.insert_temp(style_id(), style));
+        ctx.request_repaint();
+        true
+    }
+}
+
+/// Style of the page content in the current frame of an ongoing transition.
+pub fn transition_style(ctx: &egui::Context) -> Option<TransitionStyle> {
+    ctx.data(|data| data.get_temp(style_id()))
+}
+
+/// Whether the platform or the visitor requested animations to be reduced.
+fn prefers_reduced_motion(ctx: &egui::Context) -> bool {
+    #[cfg(target_arch = "wasm32")]
+    if web_sys::window()
+        .and_then(|window| {
+            window
+                .match_media("(prefers-reduced-motion: reduce)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|media_query| media_query.matches())
+    {
+        return true;
+    }
+
+    ctx.style().animation_time <= 0.0
+}
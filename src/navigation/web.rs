@@ -0,0 +1,60 @@
+//! Access to the browser location and history via the History API.
+
+use eframe::wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+/// Repaint the app whenever the user navigates through the browser history.
+pub fn install_popstate_listener(ctx: &egui::Context) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let ctx = ctx.clone();
+    let closure = Closure::<dyn Fn()>::new(move || ctx.request_repaint());
+    window
+        .add_event_listener_with_callback("popstate", closure.as_ref().unchecked_ref())
+        .ok();
+    // The listener lives for as long as the app
+    closure.forget();
+}
+
+/// Current path (with its query) and hash of the page, e.g. `("/research?q=moon", "")`.
+pub fn location() -> Option<(String, String)> {
+    let location = web_sys::window()?.location();
+    Some((
+        location.pathname().ok()? + &location.search().ok()?,
+        location.hash().ok()?,
+    ))
+}
+
+/// Navigate to the URL and record it in the browser history.
+pub fn push_url(url: &str) {
+    if let Some(history) = history() {
+        history
+            .push_state_with_url(&JsValue::NULL, "", Some(url))
+            .ok();
+    }
+}
+
+/// Replace the current URL without adding an entry to the browser history.
+pub fn replace_url(url: &str) {
+    if let Some(history) = history() {
+        history
+            .replace_state_with_url(&JsValue::NULL, "", Some(url))
+            .ok();
+    }
+}
+
+pub fn back() {
+    if let Some(history) = history() {
+        history.back().ok();
+    }
+}
+
+pub fn forward() {
+    if let Some(history) = history() {
+        history.forward().ok();
+    }
+}
+
+fn history() -> Option<web_sys::History> {
+    web_sys::window()?.history().ok()
+}
@@ -0,0 +1,68 @@
+use super::Page;
+
+/// Apps of the registered pages that are constructed on their first visit.
+///
+/// Pages that were not visited yet are constructed one per frame while the app is idle, so that
+/// switching to them later does not stall.
+#[derive(Default)]
+pub struct LazyPages {
+    apps: rustc_hash::FxHashMap<Page, Box<dyn eframe::App>>,
+    /// Time it took to construct each page, in the order of construction
+    init_times: Vec<(Page, std::time::Duration)>,
+}
+
+impl LazyPages {
+    /// App of the page, which is constructed if it does not exist yet.
+    pub fn get_or_init(&mut self, page: Page) -> &mut dyn eframe::App {
+        self.apps
+            .entry(page)
+            .or_insert_with(|| {
+                let start = web_time::Instant::now();
+                let app = page.default_app();
+                self.init_times.push((page, start.elapsed()));
+                app
+            })
+            .as_mut()
+    }
+
+    /// Construct the next page that was not visited yet if the visitor is not interacting
+    /// (starting after the first frame has been painted).
+    pub fn init_next_when_idle(&mut self, ctx: &egui::Context) {
+        let is_idle = ctx.cumulative_pass_nr() > 0
+            && ctx
+                .input(|i| i.events.is_empty() && !i.pointer.is_moving() && !i.pointer.any_down())
+            && !ctx.has_requested_repaint();
+        if !is_idle {
+            return;
+        }
+
+        if let Some(&page) = super::registry()
+            .iter()
+            .find(|page| !self.apps.contains_key(page))
+        {
+            self.get_or_init(page);
+            ctx.request_repaint();
+        }
+    }
+
+    pub fn init_times(&self) -> &[(Page, std::time::Duration)] {
+        &self.init_times
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn construct_on_first_visit() {
+        let mut pages = LazyPages::default();
+        assert!(pages.init_times().is_empty());
+
+        let page = crate::page::ABOUT;
+        pages.get_or_init(page);
+        pages.get_or_init(page);
+        assert_eq!(pages.init_times().len(), 1);
+        assert_eq!(pages.init_times()[0].0, page);
+    }
+}
@@ -1,73 +1,165 @@
-use variant::{AboutPage, BlogPage, CvPage, DemosPage, ProjectsPage, ResearchPage, TeachingPage};
+pub use lazy::LazyPages;
+pub use not_found::NotFoundPage;
+#[cfg(test)]
+pub use variant::EXCLUDED_PAGES;
+#[cfg(feature = "bibliography")]
+pub use variant::RESEARCH;
+pub use variant::{ABOUT, PAGES};
 
+mod lazy;
+mod not_found;
 mod variant;
 
-#[derive(
-    Copy, Clone, Debug, Eq, PartialEq, Hash, serde::Deserialize, serde::Serialize, strum::EnumIter,
-)]
-pub enum Page {
-    About,
-    Projects,
-    Research,
-    Teaching,
-    Cv,
-    Blog,
-    Demos,
+/// Sub-page that is shown as a tab within a page and has its own route.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Tab {
+    pub slug: &'static str,
+    pub title: &'static str,
 }
 
-impl Default for Page {
-    fn default() -> Self {
-        Self::About
+/// Description of a page that is registered in the [`registry`].
+///
+/// Each page module implements this trait once and lists its [`Page`] in [`PAGES`].
+pub trait PageDescriptor: Sync {
+    /// Unique slug that identifies the page in routes, e.g. `research`
+    fn slug(&self) -> &'static str;
+
+    fn title(&self) -> &'static str;
+
+    fn description(&self) -> &'static str;
+
+    /// Icon shown next to the title
+    fn icon(&self) -> &'static str;
+
+    /// Construct the app that shows the page
+    fn create(&self) -> Box<dyn eframe::App>;
+
+    /// Tabs of the page, the first of which is shown by default
+    fn tabs(&self) -> &'static [Tab] {
+        &[]
     }
-}
 
-impl std::fmt::Display for Page {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
+    /// Entries of the page that can be linked to directly, along with their titles
+    fn targets(&self) -> Vec<(String, crate::navigation::Target)> {
+        Vec::new()
     }
 }
 
+/// Pages that are enabled, in the order of the navigation panel.
+pub fn registry() -> &'static [Page] {
+    static REGISTRY: std::sync::OnceLock<Vec<Page>> = std::sync::OnceLock::new();
+    REGISTRY.get_or_init(|| crate::site().enabled_pages())
+}
+
+/// Handle of a registered page that compares by its slug.
+#[derive(Clone, Copy)]
+pub struct Page(&'static dyn PageDescriptor);
+
 impl Page {
+    pub const fn new(descriptor: &'static dyn PageDescriptor) -> Self {
+        Self(descriptor)
+    }
+
+    /// Find the enabled page with the given slug (case-insensitive).
+    pub fn find(slug: &str) -> Option<Self> {
+        registry()
+            .iter()
+            .copied()
+            .find(|page| page.slug().eq_ignore_ascii_case(slug))
+    }
+
+    pub fn slug(self) -> &'static str {
+        self.0.slug()
+    }
+
     pub fn title(self) -> &'static str {
-        match self {
-            Self::About => "About",
-            Self::Projects => "Projects",
-            Self::Research => "Research",
-            Self::Teaching => "Teaching",
-            Self::Cv => "CV",
-            Self::Blog => "Blog",
-            Self::Demos => "Demos",
-        }
+        self.0.title()
     }
 
     pub fn description(self) -> &'static str {
-        match self {
-            Self::About => "About me",
-            Self::Projects => "Open source projects",
-            Self::Research => "Research endeavors",
-            Self::Teaching => "Teaching materials",
-            Self::Cv => "Curriculum Vitae",
-            Self::Blog => "Blog",
-            Self::Demos => "Online demos",
-        }
+        self.0.description()
     }
 
+    pub fn icon(self) -> &'static str {
+        self.0.icon()
+    }
+
+    /// Whether the page is separated from the preceding pages in the navigation panel.
+    pub fn is_separated(self) -> bool {
+        crate::site()
+            .pages
+            .separate_menu_at
+            .iter()
+            .any(|slug| slug == self.slug())
+    }
+
+    /// External URL that replaces the page if it is registered as a short link.
     pub fn redirect_page(self) -> Option<&'static str> {
-        match self {
-            Self::Cv => Some(crate::CV_URL),
-            _ => None,
+        match &crate::navigation::redirects()
+            .get(self.slug(), crate::navigation::today())?
+            .target
+        {
+            crate::navigation::RedirectTarget::Url(url) => Some(url),
+            crate::navigation::RedirectTarget::Route(_) => None,
         }
     }
 
     pub fn default_app(self) -> Box<dyn eframe::App> {
-        match self {
-            Self::About => Box::<AboutPage>::default(),
-            Self::Projects => Box::<ProjectsPage>::default(),
-            Self::Research => Box::<ResearchPage>::default(),
-            Self::Teaching => Box::<TeachingPage>::default(),
-            Self::Cv => Box::<CvPage>::default(),
-            Self::Blog => Box::<BlogPage>::default(),
-            Self::Demos => Box::<DemosPage>::default(),
-        }
+        self.0.create()
+    }
+
+    /// Tabs of the page, the first of which is shown by default.
+    pub fn tabs(self) -> &'static [Tab] {
+        self.0.tabs()
+    }
+
+    /// Entries of the page that can be linked to directly, along with their titles.
+    pub fn targets(self) -> Vec<(String, crate::navigation::Target)> {
+        self.0.targets()
+    }
+}
+
+impl Default for Page {
+    /// The first page of the navigation panel
+    fn default() -> Self {
+        registry()[0]
+    }
+}
+
+impl PartialEq for Page {
+    fn eq(&self, other: &Self) -> bool {
+        self.slug() == other.slug()
+    }
+}
+
+impl Eq for Page {}
+
+impl std::hash::Hash for Page {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.slug().hash(state);
+    }
+}
+
+impl std::fmt::Debug for Page {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Page").field(&self.slug()).finish()
+    }
+}
+
+impl std::fmt::Display for Page {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.slug())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn unique_slugs() {
+        assert!(PAGES.iter().map(|page| page.slug()).all_unique());
     }
 }
@@ -0,0 +1,181 @@
+use crate::navigation::Route;
+
+#[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
+pub struct NotFoundPageConfig {
+    /// Maximum width of the content
+    #[builder(default = 768.0)]
+    pub max_content_width: f32,
+    /// Maximum number of suggested routes
+    #[builder(default = 5)]
+    pub max_suggestions: usize,
+}
+
+impl Default for NotFoundPageConfig {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Page shown for paths that do not match any route.
+#[must_use = "You should call .update()"]
+pub struct NotFoundPage {
+    pub cfg: NotFoundPageConfig,
+    path: String,
+    suggestions: Vec<Suggestion>,
+}
+
+struct Suggestion {
+    title: String,
+    /// Name that a visitor might type instead of the path, e.g. the title of a tab
+    name: String,
+    path: String,
+}
+
+impl NotFoundPage {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self::new_with_cfg(NotFoundPageConfig::default(), path)
+    }
+
+    pub fn new_with_cfg(cfg: NotFoundPageConfig, path: impl Into<String>) -> Self {
+        let path = path.into();
+        let query = path.trim_matches('/').to_lowercase();
+
+        // Suggest pages, tabs, entries and short links whose title or path is similar to the
+        // requested path
+        let routes = crate::page::registry().iter().flat_map(|&page| {
+            let tabs = page.tabs().iter().skip(1).map(move |tab| Suggestion {
+                title: format!("{} \u{203a} {}", page.title(), tab.title),
+                name: tab.title.to_owned(),
+                path: Route {
+                    tab: Some(tab.slug),
+                    ..page.into()
+                }
+                .to_string(),
+            });
+            std::iter::once(Suggestion {
+                title: page.title().to_owned(),
+                name: page.title().to_owned(),
+                path: Route::from(page).to_string(),
+            })
+            .chain(tabs)
+            .chain(
+                page.targets()
+                    .into_iter()
+                    .map(|(title, target)| Suggestion {
+                        name: title.clone(),
+                        title,
+                        path: Route::from(target).to_string(),
+                    }),
+            )
+        });
+        let short_links = crate::navigation::redirects()
+            .iter()
+            .filter(|redirect| !redirect.is_expired(crate::navigation::today()))
+            .map(|redirect| Suggestion {
+                title: format!("{} \u{2192} {}", redirect.slug, redirect.target),
+                name: redirect.slug.to_string(),
+                path: redirect.slug.to_string(),
+            });
+        let suggestions = crate::utils::fuzzy::closest(
+            &query,
+            routes.chain(short_links),
+            |suggestion| vec![&suggestion.name, &suggestion.path],
+            cfg.max_suggestions,
+        );
+
+        Self {
+            cfg,
+            path,
+            suggestions,
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl eframe::App for NotFoundPage {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        crate::utils::egui::ScrollableFramedCentralPanel::builder()
+            .max_content_width(self.cfg.max_content_width)
+            .build()
+            .show(ctx, |ui| self.show(ui));
+    }
+}
+
+impl NotFoundPage {
+    fn show(&self, ui: &mut egui::Ui) {
+        ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
+            crate::utils::egui::strong_heading(ui, "Page not found");
+            ui.add_space(2.0 * ui.spacing().item_spacing.y);
+
+            ui.horizontal_wrapped(|ui| {
+                ui.label("There is nothing at");
+                ui.label(egui::RichText::new(&self.path).monospace().strong());
+            });
+            ui.add_space(4.0 * ui.spacing().item_spacing.y);
+
+            if !self.suggestions.is_empty() {
+                ui.label(egui::RichText::new("Did you mean").weak());
+                ui.style_mut().override_text_style =
+                    Some(egui::TextStyle::Name("navigation_panel_display".into()));
+                for suggestion in &self.suggestions {
+                    let button = ui
+                        .add(egui::Button::new(&suggestion.title))
+                        .on_hover_text_at_pointer(format!("/{}", suggestion.path));
+                    Self::open_on_click(&button, &suggestion.path);
+                }
+                ui.add_space(4.0 * ui.spacing().item_spacing.y);
+            }
+
+            ui.style_mut().override_text_style = Some(egui::TextStyle::Button);
+            let button = ui.add(egui::Button::new("\u{e88a} Go to the homepage").frame(true));
+            Self::open_on_click(&button, "");
+        });
+    }
+
+    fn open_on_click(response: &egui::Response, path: &str) {
+        if response.clicked() {
+            crate::navigation::open_path(&response.ctx, path, true);
+        } else if response.middle_clicked() {
+            crate::navigation::open_path(&response.ctx, path, false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggest_similar_routes() {
+        let not_found = NotFoundPage::new("/abut");
+        assert_eq!(not_found.suggestions[0].path, "about");
+
+        #[cfg(feature = "bibliography")]
+        {
+            let not_found = NotFoundPage::new("/reserch");
+            assert_eq!(not_found.suggestions[0].path, "research");
+
+            // Mistyped links to entries do not match any route
+            let path = "/research/orsula2022lerning";
+            #[cfg(not(feature = "remote-content"))]
+            assert_eq!(Route::parse(path), None);
+            let not_found = NotFoundPage::new(path);
+            assert_eq!(not_found.suggestions[0].path, "research/orsula2022learning");
+
+            let not_found = NotFoundPage::new("/research/talk");
+            assert_eq!(not_found.suggestions[0].path, "research/talks");
+
+            // Titles are matched as well as paths
+            let not_found = NotFoundPage::new("/Talk");
+            assert_eq!(not_found.suggestions[0].path, "research/talks");
+            let not_found = NotFoundPage::new("/Publicaton");
+            assert_eq!(not_found.suggestions[0].path, "research/publications");
+        }
+
+        let not_found = NotFoundPage::new("/srbb");
+        assert_eq!(not_found.suggestions[0].path, "srb");
+    }
+}
@@ -1,4 +1,4 @@
-pub use page::AboutPage;
+pub use page::PAGE;
 use updates::Updates;
 
 mod page;
@@ -1,6 +1,35 @@
 use super::Updates;
+use crate::utils::{loading::Loadable, markdown::MarkdownDocument};
 
-const PAGE: crate::page::Page = crate::page::Page::About;
+pub const PAGE: crate::page::Page = crate::page::Page::new(&Descriptor);
+
+struct Descriptor;
+
+impl crate::page::PageDescriptor for Descriptor {
+    fn slug(&self) -> &'static str {
+        "about"
+    }
+
+    fn title(&self) -> &'static str {
+        "About"
+    }
+
+    fn description(&self) -> &'static str {
+        "About me"
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{e7fd}"
+    }
+
+    fn create(&self) -> Box<dyn eframe::App> {
+        Box::<AboutPage>::default()
+    }
+
+    fn targets(&self) -> Vec<(String, crate::navigation::Target)> {
+        AboutPage::targets()
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
 pub struct AboutPageConfig {
@@ -43,17 +72,20 @@ impl Default for AboutPageConfig {
 #[must_use = "You should call .update()"]
 pub struct AboutPage {
     pub cfg: AboutPageConfig,
-    pub updates: Updates,
-    commonmark_cache: egui_commonmark::CommonMarkCache,
+    pub updates: Loadable<Updates>,
+    bio: Loadable<MarkdownDocument>,
 }
 
 impl Default for AboutPage {
     fn default() -> Self {
-        static UPDATES: &str = crate::macros::include_content_str!("updates.yaml");
         Self {
             cfg: AboutPageConfig::default(),
-            updates: Updates::parse(UPDATES),
-            commonmark_cache: egui_commonmark::CommonMarkCache::default(),
+            updates: crate::macros::load_content!(
+                "updates.yaml",
+                embedded: Updates::new(crate::content::UPDATES.to_vec()),
+                parse: |src| crate::content::parse::updates(src).map(Updates::new),
+            ),
+            bio: crate::macros::load_markdown!("bio.md"),
         }
     }
 }
@@ -68,15 +100,27 @@ impl eframe::App for AboutPage {
 }
 
 impl AboutPage {
+    pub fn targets() -> Vec<(String, crate::navigation::Target)> {
+        crate::content::UPDATES
+            .iter()
+            .map(|update| {
+                (
+                    update.title.to_string(),
+                    crate::navigation::Target::Update(update.date),
+                )
+            })
+            .collect()
+    }
+
     fn show(&mut self, ui: &mut egui::Ui) {
         ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
             crate::utils::egui::strong_heading_sized(
                 ui,
-                crate::AUTHOR_NAME_FULL,
+                crate::site().author.name.as_ref(),
                 self.cfg.author_name_font_size,
             );
             ui.label(
-                egui::RichText::new(crate::AUTHOR_TITLE)
+                egui::RichText::new(crate::site().author.title.as_ref())
                     .weak()
                     .size(self.cfg.author_title_font_size),
             );
@@ -103,7 +147,7 @@ impl AboutPage {
 
             ui.add_space(6.0 * ui.spacing().item_spacing.y);
 
-            self.updates.show(ui);
+            self.updates.show(ui, |ui, updates| updates.show(ui));
         });
     }
 
@@ -118,12 +162,13 @@ impl AboutPage {
         )
     }
 
-    fn show_bio(&mut self, ui: &mut egui::Ui) -> egui::InnerResponse<()> {
-        egui_commonmark::commonmark_str!(ui, &mut self.commonmark_cache, "content/bio.md")
+    fn show_bio(&mut self, ui: &mut egui::Ui) {
+        self.bio.show(ui, |ui, bio| bio.show(ui));
     }
 
     fn show_extra_navigation_buttons(&mut self, ui: &mut egui::Ui) {
-        if crate::ENABLED_PAGES.len() > 1 {
+        let pages = crate::page::registry();
+        if pages.len() > 1 {
             ui.add_space(4.0 * ui.spacing().item_spacing.y);
             egui::Grid::new("extra_nav_buttons")
                 .spacing(egui::vec2(self.cfg.button_spacing, self.cfg.button_spacing))
@@ -136,9 +181,9 @@ impl AboutPage {
                     let mut n_columns = ((screen_width / self.cfg.max_button_width).floor()
                         as usize)
                         .max(1)
-                        .min(crate::ENABLED_PAGES.len() - 1);
+                        .min(pages.len() - 1);
                     loop {
-                        if (crate::ENABLED_PAGES.len() - 1) % n_columns == 0 {
+                        if (pages.len() - 1).is_multiple_of(n_columns) {
                             break;
                         }
                         n_columns -= 1;
@@ -150,14 +195,15 @@ impl AboutPage {
 
                     ui.style_mut().override_text_style =
                         Some(egui::TextStyle::Name("navigation_panel_display".into()));
-                    crate::ENABLED_PAGES
-                        .into_iter()
+                    pages
+                        .iter()
+                        .copied()
                         .filter(|&page| page != PAGE)
                         .enumerate()
                         .for_each(|(i, page)| {
                             let button = ui
                                 .add(
-                                    egui::Button::new(page.title())
+                                    egui::Button::new(format!("{} {}", page.icon(), page.title()))
                                         .frame(true)
                                         .rounding(self.cfg.button_spacing)
                                         .min_size(egui::Vec2::new(
@@ -167,9 +213,9 @@ impl AboutPage {
                                 )
                                 .on_hover_text_at_pointer(page.description());
                             if button.clicked() {
-                                crate::utils::egui::open_url_on_page(ui.ctx(), page, true);
+                                crate::navigation::open_route(ui.ctx(), page, true);
                             } else if button.middle_clicked() {
-                                crate::utils::egui::open_url_on_page(ui.ctx(), page, false);
+                                crate::navigation::open_route(ui.ctx(), page, false);
                             }
 
                             if (i + 1) % n_columns == 0 {
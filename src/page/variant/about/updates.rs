@@ -1,3 +1,5 @@
+use crate::content::Update;
+
 #[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
 pub struct UpdatesConfig {
     /// Font size for the updates heading
@@ -27,6 +29,9 @@ pub struct UpdatesConfig {
     /// Font size for the highlights text
     #[builder(default = 15.0)]
     pub font_size_highlight: f32,
+    /// Size of the button that copies the link to an update
+    #[builder(default = 16.0)]
+    pub copy_button_size: f32,
 }
 
 impl Default for UpdatesConfig {
@@ -37,16 +42,7 @@ impl Default for UpdatesConfig {
 
 pub struct Updates {
     pub cfg: UpdatesConfig,
-    entries: Vec<UpdateEntry>,
-}
-
-#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
-pub struct UpdateEntry {
-    pub date: chrono::NaiveDate,
-    pub title: String,
-    pub highlights: String,
-    pub description: String,
-    pub url: String,
+    entries: Vec<Update>,
 }
 
 impl Updates {
@@ -54,7 +50,7 @@ impl Updates {
         self.entries.len()
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &UpdateEntry> {
+    pub fn iter(&self) -> impl Iterator<Item = &Update> {
         self.entries.iter()
     }
 
@@ -62,13 +58,12 @@ impl Updates {
         self.entries.is_empty()
     }
 
-    pub fn parse(src: &str) -> Self {
-        Self::parse_with_cfg(UpdatesConfig::default(), src)
+    /// Updates in the given order, which is newest first for the parsed content.
+    pub fn new(entries: Vec<Update>) -> Self {
+        Self::new_with_cfg(UpdatesConfig::default(), entries)
     }
 
-    pub fn parse_with_cfg(cfg: UpdatesConfig, src: &str) -> Self {
-        let mut entries: Vec<UpdateEntry> = serde_yaml::from_str(src).unwrap();
-        entries.sort_by(|a, b| b.date.cmp(&a.date));
+    pub fn new_with_cfg(cfg: UpdatesConfig, entries: Vec<Update>) -> Self {
         Self { cfg, entries }
     }
 
@@ -92,7 +87,7 @@ impl Updates {
                 self.len() as f32 * self.cfg.vertical_spacing,
             ))
             .1;
-        let painter = ui.painter();
+        let painter = ui.painter().clone();
 
         // Draw a single line from the top to the bottom of the timeline
         painter.line_segment(
@@ -190,6 +185,33 @@ impl Updates {
                 crate::utils::egui::clickable_url(res, &update.url)
             };
 
+            // Highlight the entry if it was linked to and offer a link to it on the other side
+            // of the circle, where it is also visible on touch screens
+            let target = crate::navigation::Target::Update(update.date);
+            crate::navigation::highlight_if_targeted(ui, rect_sense, &target);
+            let copy_button_rect = egui::Rect::from_center_size(
+                circle_center
+                    - egui::vec2(
+                        side_sign
+                            * (self.cfg.circle_radius
+                                + self.cfg.text_line_offset
+                                + self.cfg.copy_button_size),
+                        0.0,
+                    ),
+                egui::Vec2::splat(2.0 * self.cfg.copy_button_size),
+            );
+            ui.put(copy_button_rect, |ui: &mut egui::Ui| {
+                ui.visuals_mut().button_frame = false;
+                crate::utils::egui::copy_button(
+                    ui,
+                    ("update_link", update.date),
+                    "\u{e157}",
+                    "Copy link",
+                    self.cfg.copy_button_size,
+                    || crate::navigation::permalink(&target.into()),
+                )
+            });
+
             // Redraw text as highlighted if hovered
             if res.hovered() {
                 painter.text(
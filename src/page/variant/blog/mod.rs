@@ -1,3 +1,3 @@
-pub use page::BlogPage;
+pub use page::PAGE;
 
 mod page;
@@ -1,6 +1,30 @@
 #![allow(dead_code)]
 
-const PAGE: crate::page::Page = crate::page::Page::Blog;
+pub const PAGE: crate::page::Page = crate::page::Page::new(&Descriptor);
+
+struct Descriptor;
+
+impl crate::page::PageDescriptor for Descriptor {
+    fn slug(&self) -> &'static str {
+        "blog"
+    }
+
+    fn title(&self) -> &'static str {
+        "Blog"
+    }
+
+    fn description(&self) -> &'static str {
+        "Blog"
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{ef42}"
+    }
+
+    fn create(&self) -> Box<dyn eframe::App> {
+        Box::<BlogPage>::default()
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
 pub struct BlogPageConfig {}
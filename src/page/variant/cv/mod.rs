@@ -1,3 +1,3 @@
-pub use page::CvPage;
+pub use page::PAGE;
 
 mod page;
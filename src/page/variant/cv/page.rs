@@ -1,3 +1,29 @@
+pub const PAGE: crate::page::Page = crate::page::Page::new(&Descriptor);
+
+struct Descriptor;
+
+impl crate::page::PageDescriptor for Descriptor {
+    fn slug(&self) -> &'static str {
+        "cv"
+    }
+
+    fn title(&self) -> &'static str {
+        "CV"
+    }
+
+    fn description(&self) -> &'static str {
+        "Curriculum Vitae"
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{e873}"
+    }
+
+    fn create(&self) -> Box<dyn eframe::App> {
+        Box::<CvPage>::default()
+    }
+}
+
 #[must_use = "You should call .update()"]
 #[derive(Default)]
 pub struct CvPage;
@@ -1,3 +1,3 @@
-pub use page::DemosPage;
+pub use page::PAGE;
 
 mod page;
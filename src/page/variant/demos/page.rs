@@ -1,6 +1,30 @@
 #![allow(dead_code)]
 
-const PAGE: crate::page::Page = crate::page::Page::Demos;
+pub const PAGE: crate::page::Page = crate::page::Page::new(&Descriptor);
+
+struct Descriptor;
+
+impl crate::page::PageDescriptor for Descriptor {
+    fn slug(&self) -> &'static str {
+        "demos"
+    }
+
+    fn title(&self) -> &'static str {
+        "Demos"
+    }
+
+    fn description(&self) -> &'static str {
+        "Online demos"
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{e1c4}"
+    }
+
+    fn create(&self) -> Box<dyn eframe::App> {
+        Box::<DemosPage>::default()
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
 pub struct DemosPageConfig {}
@@ -1,15 +1,51 @@
-pub use about::AboutPage;
-pub use blog::BlogPage;
-pub use cv::CvPage;
-pub use demos::DemosPage;
-pub use projects::ProjectsPage;
-pub use research::ResearchPage;
-pub use teaching::TeachingPage;
+pub use about::PAGE as ABOUT;
+#[cfg(feature = "bibliography")]
+pub use research::PAGE as RESEARCH;
 
 mod about;
+#[cfg(feature = "page-blog")]
 mod blog;
+#[cfg(feature = "page-cv")]
 mod cv;
+#[cfg(feature = "page-demos")]
 mod demos;
+#[cfg(feature = "page-projects")]
 mod projects;
+#[cfg(feature = "page-research")]
 mod research;
+#[cfg(feature = "page-teaching")]
 mod teaching;
+
+/// All pages that are compiled in and can be enabled, including those that are not shown.
+pub const PAGES: &[super::Page] = &[
+    about::PAGE,
+    #[cfg(feature = "page-blog")]
+    blog::PAGE,
+    #[cfg(feature = "page-cv")]
+    cv::PAGE,
+    #[cfg(feature = "page-demos")]
+    demos::PAGE,
+    #[cfg(feature = "page-projects")]
+    projects::PAGE,
+    #[cfg(feature = "page-research")]
+    research::PAGE,
+    #[cfg(feature = "page-teaching")]
+    teaching::PAGE,
+];
+
+/// Slugs of the pages that are excluded by their disabled cargo feature.
+#[cfg(test)]
+pub const EXCLUDED_PAGES: &[&str] = &[
+    #[cfg(not(feature = "page-blog"))]
+    "blog",
+    #[cfg(not(feature = "page-cv"))]
+    "cv",
+    #[cfg(not(feature = "page-demos"))]
+    "demos",
+    #[cfg(not(feature = "page-projects"))]
+    "projects",
+    #[cfg(not(feature = "page-research"))]
+    "research",
+    #[cfg(not(feature = "page-teaching"))]
+    "teaching",
+];
@@ -1,3 +1,3 @@
-pub use page::ProjectsPage;
+pub use page::PAGE;
 
 mod page;
@@ -1,6 +1,30 @@
 #![allow(dead_code)]
 
-const PAGE: crate::page::Page = crate::page::Page::Projects;
+pub const PAGE: crate::page::Page = crate::page::Page::new(&Descriptor);
+
+struct Descriptor;
+
+impl crate::page::PageDescriptor for Descriptor {
+    fn slug(&self) -> &'static str {
+        "projects"
+    }
+
+    fn title(&self) -> &'static str {
+        "Projects"
+    }
+
+    fn description(&self) -> &'static str {
+        "Open source projects"
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{e86f}"
+    }
+
+    fn create(&self) -> Box<dyn eframe::App> {
+        Box::<ProjectsPage>::default()
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
 pub struct ProjectsPageConfig {}
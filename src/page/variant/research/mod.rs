@@ -1,5 +1,7 @@
-pub use page::ResearchPage;
-use publications::Bibliography;
+pub use page::PAGE;
+#[cfg(feature = "bibliography")]
+use publications::{Bibliography, BibliographyEntry, DetailsWindow};
 
 mod page;
+#[cfg(feature = "bibliography")]
 mod publications;
@@ -1,12 +1,64 @@
-use include_dir::{include_dir, Dir};
+#[cfg(feature = "bibliography")]
+use super::{Bibliography, BibliographyEntry, DetailsWindow};
+use crate::{
+    page::Tab,
+    utils::{loading::Loadable, markdown::MarkdownDocument},
+};
 
-use super::Bibliography;
+pub const PAGE: crate::page::Page = crate::page::Page::new(&Descriptor);
 
-const PAGE: crate::page::Page = crate::page::Page::Research;
+struct Descriptor;
+
+impl crate::page::PageDescriptor for Descriptor {
+    fn slug(&self) -> &'static str {
+        "research"
+    }
+
+    fn title(&self) -> &'static str {
+        "Research"
+    }
+
+    fn description(&self) -> &'static str {
+        "Research endeavors"
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{ea4b}"
+    }
+
+    fn create(&self) -> Box<dyn eframe::App> {
+        Box::<ResearchPage>::default()
+    }
+
+    fn tabs(&self) -> &'static [Tab] {
+        ResearchPage::TABS
+    }
+
+    #[cfg(feature = "bibliography")]
+    fn targets(&self) -> Vec<(String, crate::navigation::Target)> {
+        ResearchPage::targets()
+    }
+}
+
+const OVERVIEW: Tab = Tab {
+    slug: "overview",
+    title: "Overview",
+};
+#[cfg(feature = "bibliography")]
+const PUBLICATIONS: Tab = Tab {
+    slug: "publications",
+    title: "Publications",
+};
+#[cfg(feature = "bibliography")]
+const TALKS: Tab = Tab {
+    slug: "talks",
+    title: "Talks",
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
 pub struct ResearchPageConfig {
     /// Font size for the publication heading
+    #[cfg(feature = "bibliography")]
     #[builder(default = 36.0)]
     pub publication_font_size: f32,
 }
@@ -19,20 +71,40 @@ impl Default for ResearchPageConfig {
 
 #[must_use = "You should call .update()"]
 pub struct ResearchPage {
+    #[cfg(feature = "bibliography")]
     pub cfg: ResearchPageConfig,
-    bibliography: Bibliography,
-    commonmark_cache: egui_commonmark::CommonMarkCache,
+    #[cfg(feature = "bibliography")]
+    bibliography: Loadable<Bibliography>,
+    /// Order of the publications and talks that the visitor selected
+    #[cfg(feature = "bibliography")]
+    sort_mode: crate::content::SortMode,
+    /// Filter of the publications and talks, which is kept in the query of the route
+    #[cfg(feature = "bibliography")]
+    filter: crate::content::PublicationFilter,
+    /// Query of the route that the filter was last synchronized with
+    #[cfg(feature = "bibliography")]
+    filter_query: Option<String>,
+    overview: Loadable<MarkdownDocument>,
 }
 
 impl Default for ResearchPage {
     fn default() -> Self {
-        static BIB: &str = crate::macros::include_content_str!("publications/bibliography.bib");
-        static THUMBNAILS: Dir =
-            include_dir!("$CARGO_MANIFEST_DIR/content/publications/thumbnails");
         Self {
+            #[cfg(feature = "bibliography")]
             cfg: ResearchPageConfig::default(),
-            bibliography: Bibliography::parse(BIB, Some(&THUMBNAILS)),
-            commonmark_cache: egui_commonmark::CommonMarkCache::default(),
+            #[cfg(feature = "bibliography")]
+            bibliography: crate::macros::load_content!(
+                "publications/bibliography.bib",
+                embedded: Bibliography::new(crate::content::PUBLICATIONS.to_vec()),
+                parse: Bibliography::parse,
+            ),
+            #[cfg(feature = "bibliography")]
+            sort_mode: crate::content::SortMode::default(),
+            #[cfg(feature = "bibliography")]
+            filter: crate::content::PublicationFilter::default(),
+            #[cfg(feature = "bibliography")]
+            filter_query: None,
+            overview: crate::macros::load_markdown!("research.md"),
         }
     }
 }
@@ -41,22 +113,133 @@ impl eframe::App for ResearchPage {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         crate::utils::egui::ScrollableFramedCentralPanel::default().show(ctx, |ui| {
             crate::utils::egui::centered_strong_heading(ui, PAGE.title());
-            egui_commonmark::commonmark_str!(ui, &mut self.commonmark_cache, "content/research.md");
-
-            ui.add_space(16.0);
-
-            self.show_publications(ui);
+            match crate::navigation::tab_strip(ui, PAGE) {
+                #[cfg(feature = "bibliography")]
+                Some(PUBLICATIONS) => self.show_publications(ui),
+                #[cfg(feature = "bibliography")]
+                Some(TALKS) => self.show_talks(ui),
+                _ => {
+                    self.overview.show(ui, |ui, overview| overview.show(ui));
+                }
+            }
         });
     }
 }
 
 impl ResearchPage {
-    fn show_publications(&self, ui: &mut egui::Ui) {
+    pub const TABS: &[Tab] = &[
+        OVERVIEW,
+        #[cfg(feature = "bibliography")]
+        PUBLICATIONS,
+        #[cfg(feature = "bibliography")]
+        TALKS,
+    ];
+
+    #[cfg(feature = "bibliography")]
+    pub fn targets() -> Vec<(String, crate::navigation::Target)> {
+        crate::content::PUBLICATIONS
+            .iter()
+            .map(|publication| {
+                (
+                    publication.title.to_string(),
+                    crate::navigation::Target::Publication(publication.key.to_string()),
+                )
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "bibliography")]
+    fn show_publications(&mut self, ui: &mut egui::Ui) {
         crate::utils::egui::centered_strong_heading_sized(
             ui,
             "Publications",
             self.cfg.publication_font_size,
         );
-        self.bibliography.show(ui);
+        self.show_bibliography(ui, "publications", |_| true);
+    }
+
+    #[cfg(feature = "bibliography")]
+    fn show_talks(&mut self, ui: &mut egui::Ui) {
+        crate::utils::egui::centered_strong_heading_sized(
+            ui,
+            "Talks",
+            self.cfg.publication_font_size,
+        );
+        self.show_bibliography(ui, "talks", |entry| entry.is_talk());
+    }
+
+    /// Show the filtered entries of the bibliography that satisfy the predicate, which are
+    /// exported to files named after the given stem.
+    #[cfg(feature = "bibliography")]
+    fn show_bibliography(
+        &mut self,
+        ui: &mut egui::Ui,
+        file_stem: &str,
+        predicate: impl Fn(&BibliographyEntry) -> bool,
+    ) {
+        // Follow the filter of the route, e.g. after opening a shared link
+        let route = crate::navigation::visible_route(ui.ctx());
+        if route.query != self.filter_query {
+            self.filter = crate::content::PublicationFilter::from_params(
+                crate::navigation::decode_query(route.query.as_deref().unwrap_or_default()),
+            );
+            self.filter_query = route.query.clone();
+        }
+
+        self.show_sort_mode(ui);
+        let (sort_mode, filter) = (self.sort_mode, &mut self.filter);
+        let details = self.bibliography.show(ui, |ui, bibliography| {
+            if bibliography.sort_mode() != sort_mode {
+                bibliography.sort(sort_mode);
+            }
+            bibliography.show_filter(ui, filter);
+            bibliography.show_export_menu(ui, file_stem, |entry| {
+                predicate(entry) && filter.matches(entry)
+            });
+            bibliography.show_filtered(ui, filter, &predicate);
+
+            // Routes to an entry show its details on top of the list
+            match &route.target {
+                Some(crate::navigation::Target::Publication(key)) => {
+                    Some(bibliography.show_details(ui.ctx(), key))
+                }
+                _ => None,
+            }
+        });
+        match details.flatten() {
+            // Closing the details returns to the list without adding an entry to the history
+            Some(DetailsWindow::Closed) => crate::navigation::replace_route(
+                ui.ctx(),
+                crate::navigation::Route {
+                    target: None,
+                    ..route
+                },
+            ),
+            Some(DetailsWindow::UnknownEntry) => {
+                crate::navigation::open_not_found(ui.ctx(), route.to_string());
+            }
+            Some(DetailsWindow::Open) | None => {}
+        }
+
+        // Keep the filter in the URL, so that the filtered view can be shared
+        let query = crate::navigation::encode_query(self.filter.params());
+        if query != self.filter_query {
+            self.filter_query = query.clone();
+            crate::navigation::replace_query(ui.ctx(), query);
+        }
+    }
+
+    #[cfg(feature = "bibliography")]
+    fn show_sort_mode(&mut self, ui: &mut egui::Ui) {
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            egui::ComboBox::from_id_salt("research_sort_mode")
+                .selected_text(self.sort_mode.title())
+                .show_ui(ui, |ui| {
+                    for &sort_mode in crate::content::SortMode::ALL {
+                        ui.selectable_value(&mut self.sort_mode, sort_mode, sort_mode.title());
+                    }
+                });
+            ui.label(egui::RichText::new("Sort by").weak());
+        });
     }
 }
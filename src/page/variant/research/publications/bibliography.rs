@@ -1,19 +1,27 @@
-use biblatex::ChunksExt;
 use itertools::Itertools;
 
 use super::{BibliographyEntry, BibliographyEntryConfig};
+#[cfg(any(hot_reload, feature = "remote-content"))]
+use crate::content::parse::ParseError;
+use crate::content::{ExportFormat, Publication, PublicationFilter, SortMode};
 
 #[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
 pub struct BibliographyConfig {
-    /// Font size for the year
+    /// Font size for the heading of each group (e.g. the year)
     #[builder(default = 24.0)]
-    pub year_font_size: f32,
-    /// Spacing before and after each year (spacing before the first year is ignored)
+    pub group_font_size: f32,
+    /// Spacing before and after each group heading (spacing before the first one is ignored)
     #[builder(default = [8.0, 2.0])]
-    pub year_spacing: [f32; 2],
+    pub group_spacing: [f32; 2],
+    /// Width of the search field of the filter
+    #[builder(default = 200.0)]
+    pub search_width: f32,
     /// Spacing after each entry (spacing after the last entry is ignored)
     #[builder(default = 12.0)]
     pub entry_spacing: f32,
+    /// Maximum width of the window with the details of an entry
+    #[builder(default = 720.0)]
+    pub details_width: f32,
     /// Configuration for each bibliography entry
     #[builder(default)]
     pub entry_cfg: BibliographyEntryConfig,
@@ -29,172 +37,337 @@ impl Default for BibliographyConfig {
 pub struct Bibliography {
     pub cfg: BibliographyConfig,
     bib: Vec<BibliographyEntry>,
+    sort_mode: SortMode,
+    /// Entries that could not be parsed at runtime, which are shown as error cards
+    #[cfg(any(hot_reload, feature = "remote-content"))]
+    errors: Vec<ParseError>,
 }
 
-impl Bibliography {
-    pub fn len(&self) -> usize {
-        self.bib.len()
-    }
+/// State of the window with the details of an entry after it was shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetailsWindow {
+    Open,
+    Closed,
+    /// The bibliography has no entry with the key, e.g. because the content that was loaded at
+    /// runtime differs from the embedded one that the routes are checked against
+    UnknownEntry,
+}
 
+impl Bibliography {
     pub fn iter(&self) -> impl Iterator<Item = &BibliographyEntry> {
         self.bib.iter()
     }
 
-    pub fn parse(src: &str, thumbnails_dir: Option<&'static include_dir::Dir>) -> Self {
-        Self::parse_with_cfg(BibliographyConfig::default(), src, thumbnails_dir)
+    pub fn new(publications: Vec<Publication>) -> Self {
+        Self::new_with_cfg(BibliographyConfig::default(), publications)
     }
 
-    pub fn parse_with_cfg(
-        cfg: BibliographyConfig,
-        src: &str,
-        thumbnails_dir: Option<&'static include_dir::Dir>,
-    ) -> Self {
-        // Parse the bibliography
-        let unsorted_bib = biblatex::Bibliography::parse(src).unwrap();
-
-        // Sort and collect the bibliography entries with their thumbnails
-        let bib = unsorted_bib
+    pub fn new_with_cfg(cfg: BibliographyConfig, publications: Vec<Publication>) -> Self {
+        // Collect the bibliography entries with their thumbnails
+        let bib = publications
             .into_iter()
-            .sorted_by_key(|entry| match entry.date().unwrap() {
-                biblatex::PermissiveType::Typed(date) => match date.value {
-                    biblatex::DateValue::At(date)
-                    | biblatex::DateValue::After(date)
-                    | biblatex::DateValue::Before(date)
-                    | biblatex::DateValue::Between(date, _) => format!(
-                        "{}{}{}",
-                        date.year,
-                        date.month.unwrap_or(0),
-                        date.day.unwrap_or(0)
-                    ),
-                },
-                biblatex::PermissiveType::Chunks(chunks) => chunks
-                    .parse::<String>()
-                    .unwrap()
-                    .chars()
-                    .filter(|c| c.is_numeric())
-                    .collect::<String>(),
-            })
-            // Newest entries first
-            .rev()
-            .map(|entry| {
-                let thumbnail = Self::get_thumbnail(thumbnails_dir, &entry.key);
-                BibliographyEntry::new_with_cfg(cfg.entry_cfg, entry, thumbnail)
+            .map(|publication| {
+                let thumbnail = thumbnail(&publication.key);
+                BibliographyEntry::new_with_cfg(cfg.entry_cfg, publication, thumbnail)
             })
             .collect();
 
-        Self { cfg, bib }
+        let mut bibliography = Self {
+            cfg,
+            bib,
+            sort_mode: SortMode::default(),
+            #[cfg(any(hot_reload, feature = "remote-content"))]
+            errors: Vec::new(),
+        };
+        bibliography.sort(SortMode::default());
+        bibliography
     }
 
-    pub fn show(&self, ui: &mut egui::Ui) {
-        self.iter()
-            .enumerate()
-            .fold(0, |previous_entry_year, (i, entry)| {
-                let year = entry.year().unwrap();
-                if year != previous_entry_year {
-                    // Add spacing before the year (except for the first year)
-                    if i > 0 {
-                        ui.add_space(self.cfg.year_spacing[0]);
-                    }
+    pub fn sort_mode(&self) -> SortMode {
+        self.sort_mode
+    }
 
-                    // Add the year
-                    crate::utils::egui::heading_sized(
-                        ui,
-                        year.to_string(),
-                        self.cfg.year_font_size,
-                    );
+    pub fn sort(&mut self, sort_mode: SortMode) {
+        self.sort_mode = sort_mode;
+        self.bib.sort_by(|a, b| sort_mode.compare(a, b));
+    }
 
-                    // Add spacing after the year
-                    ui.add_space(self.cfg.year_spacing[1]);
-                }
+    /// Parse the bibliography from BibTeX, keeping the malformed entries as errors.
+    #[cfg(any(hot_reload, feature = "remote-content"))]
+    pub fn parse(src: &str) -> Result<Self, ParseError> {
+        let (publications, errors) = crate::content::parse::publications(src)?
+            .into_iter()
+            .partition_result();
+        Ok(Self {
+            errors,
+            ..Self::new(publications)
+        })
+    }
 
-                // Show the entry
-                entry.show(ui);
+    /// Export the entries that satisfy the predicate in the order in which they are shown.
+    pub fn export(
+        &self,
+        format: ExportFormat,
+        predicate: impl Fn(&BibliographyEntry) -> bool,
+    ) -> String {
+        format.export(
+            self.iter()
+                .filter(|entry| predicate(entry))
+                .map(|entry| &**entry),
+        )
+    }
+
+    /// Show a menu for downloading the whole bibliography, or only the entries that satisfy the
+    /// predicate, to a file named after the given stem.
+    pub fn show_export_menu(
+        &self,
+        ui: &mut egui::Ui,
+        file_stem: &str,
+        predicate: impl Fn(&BibliographyEntry) -> bool,
+    ) {
+        let ctx = ui.ctx().clone();
+        let save = |format: ExportFormat, name: &str, contents: String| {
+            crate::utils::download::save_file(
+                &ctx,
+                &format!("{name}.{}", format.extension()),
+                format.mime_type(),
+                &contents,
+            );
+        };
+        let is_filtered = self.iter().any(|entry| !predicate(entry));
 
-                // Add spacing between entries (except for the last one)
-                if i < self.len() - 1 {
-                    ui.add_space(self.cfg.entry_spacing);
+        ui.menu_button("\u{f090} Export", |ui| {
+            if ui.button("Download all as .bib").clicked() {
+                save(
+                    ExportFormat::Bibtex,
+                    "bibliography",
+                    self.export(ExportFormat::Bibtex, |_| true),
+                );
+                ui.close_menu();
+            }
+            ui.separator();
+            if is_filtered {
+                ui.label(egui::RichText::new("Shown entries").weak());
+                for &format in ExportFormat::ALL {
+                    if ui.button(format.title()).clicked() {
+                        save(format, file_stem, self.export(format, &predicate));
+                        ui.close_menu();
+                    }
+                }
+                ui.label(egui::RichText::new("All entries").weak());
+            }
+            for &format in ExportFormat::ALL {
+                if ui.button(format.title()).clicked() {
+                    save(format, "bibliography", self.export(format, |_| true));
+                    ui.close_menu();
                 }
+            }
+        });
+    }
+
+    /// Show the details of the entry with the given key in a window, and return whether the
+    /// window is still open (it closes via its button or the Escape key) or the key is unknown.
+    pub fn show_details(&self, ctx: &egui::Context, key: &str) -> DetailsWindow {
+        let Some(entry) = self.iter().find(|entry| entry.key == key) else {
+            return DetailsWindow::UnknownEntry;
+        };
 
-                year
+        let mut open = !ctx.input(|i| i.key_pressed(egui::Key::Escape));
+        let screen_rect = ctx.screen_rect();
+        let width = (screen_rect.width() - 32.0).min(self.cfg.details_width);
+        egui::Window::new("Publication")
+            .id(egui::Id::new("publication_details"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .default_width(width)
+            .max_width(width)
+            .max_height(screen_rect.height() * 0.8)
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| entry.show_details(ui));
             });
+        if open {
+            DetailsWindow::Open
+        } else {
+            DetailsWindow::Closed
+        }
     }
 
-    fn get_thumbnail(
-        thumbnails_dir: Option<&'static include_dir::Dir>,
-        key: &str,
-    ) -> Option<egui::ImageSource<'static>> {
-        thumbnails_dir?
-            .files()
-            .find(|file| file.path().file_stem().is_some_and(|stem| stem == key))
-            .map(|file| egui::ImageSource::Bytes {
-                uri: std::borrow::Cow::Owned(format!(
-                    "bytes://{}",
-                    file.path()
-                        .file_name()
-                        .unwrap_or_else(|| unreachable!())
-                        .to_string_lossy()
-                )),
-                bytes: egui::load::Bytes::Static(file.contents()),
-            })
+    /// Show a row of widgets for editing the filter, whose options are derived from the entries.
+    pub fn show_filter(&self, ui: &mut egui::Ui, filter: &mut PublicationFilter) {
+        ui.horizontal_wrapped(|ui| {
+            // Search across the title, authors, venue and keywords
+            ui.label("\u{e8b6}");
+            let search = ui.add(
+                egui::TextEdit::singleline(&mut filter.text)
+                    .hint_text("Search")
+                    .desired_width(self.cfg.search_width),
+            );
+            // Escape only leaves the search field instead of also closing the details of an entry
+            if search.lost_focus() {
+                ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape));
+            }
+
+            // Range of years
+            if let Some((min, max)) = self
+                .iter()
+                .filter_map(|entry| entry.date.year)
+                .minmax()
+                .into_option()
+                .filter(|(min, max)| min < max)
+            {
+                // Unset bounds stay open-ended (also beyond the years of the entries) until moved,
+                // and moving a slider to its end unsets the bound again
+                let mut from = filter.from_year.unwrap_or(min).clamp(min, max);
+                let mut to = filter.to_year.unwrap_or(max).clamp(from, max);
+                if ui
+                    .add(egui::Slider::new(&mut from, min..=max).text("to"))
+                    .changed()
+                {
+                    filter.from_year = (from != min).then_some(from);
+                    filter.to_year = filter.to_year.map(|to| to.max(from));
+                }
+                if ui.add(egui::Slider::new(&mut to, from..=max)).changed() {
+                    filter.to_year = (to != max).then_some(to);
+                }
+            }
+
+            // Kinds of publications
+            for &kind in PublicationFilter::KINDS {
+                let selected = filter.kinds.contains(&kind);
+                if ui.selectable_label(selected, kind.title()).clicked() {
+                    if selected {
+                        filter.kinds.remove(&kind);
+                    } else {
+                        filter.kinds.insert(kind);
+                    }
+                }
+            }
+
+            // Co-author, excluding the site author who is among the authors of most entries
+            let site_author = &crate::site().author;
+            let coauthors = self
+                .iter()
+                .flat_map(|entry| entry.authors.iter())
+                .filter(|author| !author.is(&site_author.initials, &site_author.surname))
+                .sorted_by(|a, b| (&a.name, &a.given_name).cmp(&(&b.name, &b.given_name)))
+                .map(|author| author.full_name())
+                .dedup()
+                .collect_vec();
+            egui::ComboBox::from_id_salt("bibliography_coauthor")
+                .selected_text(filter.coauthor.as_deref().unwrap_or("Any co-author"))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut filter.coauthor, None, "Any co-author");
+                    for coauthor in coauthors {
+                        let text = coauthor.clone();
+                        ui.selectable_value(&mut filter.coauthor, Some(coauthor), text);
+                    }
+                });
+
+            if filter.is_active() && ui.button("Clear").clicked() {
+                *filter = PublicationFilter::default();
+            }
+        });
+        ui.add_space(self.cfg.entry_spacing);
+    }
+
+    /// Show only the entries that satisfy both the predicate and the filter, grouped according
+    /// to the sort mode and with the matches of the search text highlighted in their titles.
+    pub fn show_filtered(
+        &self,
+        ui: &mut egui::Ui,
+        filter: &PublicationFilter,
+        predicate: impl Fn(&BibliographyEntry) -> bool,
+    ) {
+        #[cfg(any(hot_reload, feature = "remote-content"))]
+        for err in &self.errors {
+            crate::utils::egui::error_card(
+                ui,
+                format!(
+                    "Failed to show the entry `{}`",
+                    err.key().unwrap_or_default()
+                ),
+                |ui| ui.monospace(format!("content/publications/bibliography.bib:{err}")),
+            );
+            ui.add_space(self.cfg.entry_spacing);
+        }
+
+        let entries = self
+            .iter()
+            .filter(|entry| predicate(entry) && filter.matches(entry))
+            .collect_vec();
+        if entries.is_empty() && filter.is_active() {
+            ui.vertical_centered(|ui| ui.label(egui::RichText::new("No matching entries").weak()));
+        }
+        let mut previous_group = None;
+        for (i, entry) in entries.iter().enumerate() {
+            let group = self.sort_mode.group(entry);
+            if previous_group.as_ref() != Some(&group) {
+                // Add spacing before the group (except for the first group)
+                if i > 0 {
+                    ui.add_space(self.cfg.group_spacing[0]);
+                }
+
+                // Add the group heading
+                crate::utils::egui::heading_sized(ui, group.as_str(), self.cfg.group_font_size);
+
+                // Add spacing after the group heading
+                ui.add_space(self.cfg.group_spacing[1]);
+                previous_group = Some(group);
+            }
+
+            // Show the entry
+            entry.show(ui, &filter.highlights(&entry.title));
+
+            // Add spacing between entries (except for the last one)
+            if i < entries.len() - 1 {
+                ui.add_space(self.cfg.entry_spacing);
+            }
+        }
     }
 }
 
+/// Thumbnail of the entry with the given key from `content/publications/thumbnails`.
+#[cfg(not(any(hot_reload, feature = "remote-content")))]
+fn thumbnail(key: &str) -> Option<egui::ImageSource<'static>> {
+    crate::content::embedded_thumbnail(key).map(|(file_name, contents)| egui::ImageSource::Bytes {
+        uri: std::borrow::Cow::Owned(format!("bytes://{file_name}")),
+        bytes: egui::load::Bytes::Static(contents),
+    })
+}
+
+/// Thumbnails are read from disk, so that new ones show up without a rebuild.
+#[cfg(hot_reload)]
+fn thumbnail(key: &str) -> Option<egui::ImageSource<'static>> {
+    let dir = crate::utils::hot_reload::content_dir().join("publications/thumbnails");
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .find(|file| file.path().file_stem().is_some_and(|stem| stem == key))
+        .map(|file| egui::ImageSource::Uri(crate::utils::hot_reload::file_uri(&file.path()).into()))
+}
+
+/// Thumbnails cannot be listed on the server, so they are expected to be PNG images.
+#[cfg(feature = "remote-content")]
+fn thumbnail(key: &str) -> Option<egui::ImageSource<'static>> {
+    Some(crate::utils::loading::content_image(&format!(
+        "publications/thumbnails/{key}.png"
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn parse_bib() {
-        const BIB: &str = indoc::indoc! {"
-            @inproceedings{orsula2022learning,
-                title = {{Learning to Grasp on the Moon from 3D Octree Observations with Deep Reinforcement Learning}},
-                author = {Andrej Orsula and Simon Bøgh and Miguel Olivares-Mendez and Carol Martinez},
-                booktitle = {2022 IEEE/RSJ International Conference on Intelligent Robots and Systems (IROS)},
-                year = {2022},
-                organization = {IEEE},
-                page = {4112--4119},
-                repository = {https://github.com/AndrejOrsula/drl_grasping},
-                article_pdf = {https://arxiv.org/pdf/2208.00818},
-                publication_url = {https://ieeexplore.ieee.org/document/9981661},
-            }
-        "};
-
-        let bibliography = Bibliography::parse(BIB, None);
-        let entry = bibliography.iter().next().unwrap();
-
-        assert_eq!(entry.title().unwrap(), "Learning to Grasp on the Moon from 3D Octree Observations with Deep Reinforcement Learning");
-        assert_eq!(
-            entry
-                .author()
-                .unwrap()
-                .iter()
-                .map(std::string::ToString::to_string)
-                .collect_vec(),
-            [
-                "Andrej Orsula",
-                "Simon Bøgh",
-                "Miguel Olivares-Mendez",
-                "Carol Martinez"
-            ]
-        );
-        assert_eq!(entry.year().unwrap(), 2022);
-        assert_eq!(
-            entry.venue().unwrap(),
-            "2022 IEEE/RSJ International Conference on Intelligent Robots and Systems (IROS)"
-        );
-        assert!(entry.homepage().is_err());
-        assert_eq!(
-            entry.publication_url().unwrap(),
-            "https://ieeexplore.ieee.org/document/9981661"
-        );
-        assert_eq!(
-            entry.article_pdf().unwrap(),
-            "https://arxiv.org/pdf/2208.00818"
-        );
-        assert!(entry.article_html().is_err());
-        assert_eq!(
-            entry.repository().unwrap(),
-            "https://github.com/AndrejOrsula/drl_grasping"
-        );
+    fn keep_open_ended_years() {
+        let bibliography = Bibliography::new(crate::content::PUBLICATIONS.to_vec());
+        let shared = PublicationFilter::from_params([("to".to_owned(), "2023".to_owned())]);
+        let mut filter = shared.clone();
+        let _ = egui::Context::default().run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| bibliography.show_filter(ui, &mut filter));
+        });
+        assert_eq!(filter, shared);
     }
 }
@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use crate::content::{CitationStyle, ExportFormat, Publication};
 
 #[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
 pub struct BibliographyEntryConfig {
@@ -14,6 +14,21 @@ pub struct BibliographyEntryConfig {
     pub button_spacing: f32,
     #[builder(default = 26.0)]
     pub button_size: f32,
+    /// Size of the thumbnail in the detail view
+    #[builder(default = 220.0)]
+    pub details_thumbnail_size: f32,
+    /// Font size of the title in the detail view
+    #[builder(default = 26.0)]
+    pub details_title_font_size: f32,
+    /// Font size of the section headings in the detail view (e.g. the abstract)
+    #[builder(default = 18.0)]
+    pub details_heading_font_size: f32,
+    /// Spacing before each section of the detail view
+    #[builder(default = 12.0)]
+    pub details_spacing: f32,
+    /// Width of the previews of the videos in the detail view
+    #[builder(default = 240.0)]
+    pub video_preview_width: f32,
 }
 
 impl Default for BibliographyEntryConfig {
@@ -25,12 +40,12 @@ impl Default for BibliographyEntryConfig {
 #[must_use = "You should call .show()"]
 pub struct BibliographyEntry {
     pub cfg: BibliographyEntryConfig,
-    entry: biblatex::Entry,
+    entry: Publication,
     thumbnail: Option<egui::ImageSource<'static>>,
 }
 
 impl std::ops::Deref for BibliographyEntry {
-    type Target = biblatex::Entry;
+    type Target = Publication;
     fn deref(&self) -> &Self::Target {
         &self.entry
     }
@@ -39,7 +54,7 @@ impl std::ops::Deref for BibliographyEntry {
 impl BibliographyEntry {
     pub fn new_with_cfg(
         cfg: BibliographyEntryConfig,
-        entry: biblatex::Entry,
+        entry: Publication,
         thumbnail: Option<egui::ImageSource<'static>>,
     ) -> Self {
         Self {
@@ -49,22 +64,43 @@ impl BibliographyEntry {
         }
     }
 
-    pub fn show(&self, ui: &mut egui::Ui) {
-        ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
-            ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
-                self.show_thumbnail(ui);
-                ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
-                    self.show_title(ui);
-                    self.show_authors(ui);
-                    self.show_venue(ui);
-                    self.show_buttons(ui);
+    /// Show the entry with the given byte ranges of its title highlighted (e.g. search matches).
+    pub fn show(&self, ui: &mut egui::Ui, highlights: &[std::ops::Range<usize>]) {
+        let response = ui
+            .with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
+                ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
+                    self.show_thumbnail(ui);
+                    ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
+                        self.show_title(ui, highlights);
+                        self.show_authors(ui);
+                        self.show_venue(ui);
+                        self.show_buttons(ui);
+                    });
                 });
-            });
-        });
+            })
+            .response;
+        crate::navigation::highlight_if_targeted(ui, response.rect, &self.target());
+    }
+
+    /// Navigation target that links directly to this entry.
+    pub fn target(&self) -> crate::navigation::Target {
+        crate::navigation::Target::Publication(self.key.to_string())
+    }
+
+    /// Thumbnail of the entry, unless it failed to load.
+    fn loaded_thumbnail(&self, ui: &egui::Ui) -> Option<&egui::ImageSource<'static>> {
+        // Thumbnails that are fetched from the server might not exist
+        self.thumbnail.as_ref().filter(|thumbnail| match thumbnail {
+            egui::ImageSource::Uri(uri) => ui
+                .ctx()
+                .try_load_image(uri, egui::SizeHint::default())
+                .is_ok(),
+            _ => true,
+        })
     }
 
     fn show_thumbnail(&self, ui: &mut egui::Ui) {
-        if let Some(thumbnail) = &self.thumbnail {
+        if let Some(thumbnail) = self.loaded_thumbnail(ui) {
             let image = ui.add(
                 egui::Image::new(thumbnail.clone())
                     .sense(egui::Sense::click())
@@ -74,106 +110,89 @@ impl BibliographyEntry {
                         self.cfg.thumbnail_size,
                     )),
             );
-            self.maybe_clickable_homepage_or_publication_url(image);
+            self.clickable_details(image);
         } else {
             ui.add_space(self.cfg.thumbnail_size + ui.spacing().item_spacing.x);
         }
     }
 
-    fn show_title(&self, ui: &mut egui::Ui) {
-        let title = crate::utils::egui::strong_heading_sized(
-            ui,
-            self.title().unwrap(),
-            self.cfg.title_font_size,
-        );
-        self.maybe_clickable_homepage_or_publication_url(title);
+    fn show_title(&self, ui: &mut egui::Ui, highlights: &[std::ops::Range<usize>]) {
+        let title = if highlights.is_empty() {
+            crate::utils::egui::strong_heading_sized(
+                ui,
+                self.title.as_ref(),
+                self.cfg.title_font_size,
+            )
+        } else {
+            let style = ui.style().clone();
+            let mut job = egui::text::LayoutJob::default();
+            let mut append = |range: std::ops::Range<usize>, highlighted: bool| {
+                let mut text = egui::RichText::new(&self.title[range])
+                    .heading()
+                    .strong()
+                    .size(self.cfg.title_font_size);
+                if highlighted {
+                    text = text.background_color(style.visuals.selection.bg_fill);
+                }
+                text.append_to(
+                    &mut job,
+                    &style,
+                    egui::FontSelection::Default,
+                    egui::Align::Center,
+                );
+            };
+            let mut end = 0;
+            for range in highlights {
+                append(end..range.start, false);
+                append(range.clone(), true);
+                end = range.end;
+            }
+            append(end..self.title.len(), false);
+            ui.label(job)
+        };
+        self.clickable_details(title);
     }
 
     fn show_authors(&self, ui: &mut egui::Ui) {
-        let authors = self.author().unwrap();
-        let highlighted_name_index = authors
-            .iter()
-            .position(|author| {
-                author.given_name.starts_with(crate::AUTHOR_INITIALS[0])
-                    && author.name == crate::AUTHOR_SURNAME
-            })
-            .unwrap();
+        let site_author = &crate::site().author;
+        let authors = &self.authors;
+        if authors.is_empty() {
+            return;
+        }
+        // The site author is not necessarily among the authors, in which case none is highlighted
+        let highlighted_name_index = self.author_index(&site_author.initials, &site_author.surname);
+        let name = |index: usize, suffix: &str| {
+            if highlighted_name_index == Some(index) {
+                egui::RichText::new(format!("{}{suffix}", site_author.short_name)).strong()
+            } else {
+                egui::RichText::new(format!("{}{suffix}", authors[index].short_name()))
+            }
+        };
 
         ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
             ui.spacing_mut().item_spacing.x = 0.0;
             match authors.len() {
                 1 => {
-                    ui.label(egui::RichText::new(crate::AUTHOR_NAME_SHORT).strong());
+                    ui.label(name(0, ""));
                 }
                 _list_all
                     if ui.ctx().available_rect().width() - self.cfg.thumbnail_size
                         > authors.len() as f32 * self.cfg.min_spacing_per_author =>
                 {
-                    // Names before the highlighted author
-                    if highlighted_name_index > 0 {
-                        ui.label(egui::RichText::new(format!(
-                            "{}, ",
-                            authors
-                                .iter()
-                                .take(highlighted_name_index)
-                                .map(|author| format!(
-                                    "{} {}",
-                                    author.given_name.chars().next().unwrap(),
-                                    author.name
-                                ))
-                                .join(", "),
-                        )));
-                    }
-
-                    // Highlighted author
-                    ui.label(egui::RichText::new(crate::AUTHOR_NAME_SHORT).strong());
-
-                    // Names after the highlighted author
-                    if authors.len() > highlighted_name_index + 1 {
-                        ui.label(egui::RichText::new(format!(
-                            ", {}",
-                            authors
-                                .iter()
-                                .skip(highlighted_name_index + 1)
-                                .map(|author| format!(
-                                    "{} {}",
-                                    author.given_name.chars().next().unwrap(),
-                                    author.name
-                                ))
-                                .join(", "),
-                        )));
+                    for index in 0..authors.len() {
+                        if index > 0 {
+                            ui.label(", ");
+                        }
+                        ui.label(name(index, ""));
                     }
                 }
                 _et_al => {
-                    let first_author = if highlighted_name_index == 0 {
-                        ui.label(
-                            egui::RichText::new(format!("{} et al.", crate::AUTHOR_NAME_SHORT))
-                                .strong(),
-                        )
-                    } else {
-                        ui.label(format!(
-                            "{} {} et al.",
-                            authors[0].given_name.chars().next().unwrap(),
-                            authors[0].name
-                        ))
-                    };
-
                     // List the remaining authors on hover
-                    first_author.on_hover_ui(|ui| {
+                    ui.label(name(0, " et al.")).on_hover_ui(|ui| {
                         ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
-                            authors.iter().skip(1).enumerate().for_each(|(i, author)| {
-                                if highlighted_name_index == i + 1 {
-                                    ui.label(
-                                        egui::RichText::new(crate::AUTHOR_NAME_SHORT).strong(),
-                                    );
-                                } else {
-                                    ui.label(format!(
-                                        "{} {}",
-                                        author.given_name.chars().next().unwrap(),
-                                        author.name
-                                    ));
-                                }
-                            });
+                            for index in 1..authors.len() {
+                                ui.label(name(index, ""));
+                            }
                         });
                     });
                 }
@@ -182,53 +201,16 @@ impl BibliographyEntry {
     }
 
     fn show_venue(&self, ui: &mut egui::Ui) {
-        let venue = match self.venue().unwrap() {
-            abbreviated if abbreviated.ends_with(')') => {
-                format!(
-                    "{} {}",
-                    abbreviated
-                        .split('(')
-                        .next_back()
-                        .unwrap()
-                        .split(')')
-                        .next()
-                        .unwrap()
-                        .to_owned(),
-                    self.year().unwrap()
-                )
-            }
-            arxiv_preprint if arxiv_preprint.to_lowercase().contains("arxiv") => {
-                "arXiv preprint".to_string()
-            }
-            preprint if preprint.to_lowercase().contains("preprint") => "Preprint".to_string(),
-            other => other,
-        };
-        ui.label(egui::RichText::new(venue).weak());
+        if let Some(venue) = self.short_venue() {
+            ui.label(egui::RichText::new(venue).weak());
+        }
     }
 
     fn show_buttons(&self, ui: &mut egui::Ui) {
         ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
             ui.spacing_mut().item_spacing.x = self.cfg.button_spacing;
 
-            // Simple hyperlink buttons
-            macro_rules! maybe_show_simple_button {
-                ($link:expr, $symbol:expr, $hover_text:expr) => {
-                    if let Ok(link) = $link {
-                        let button = ui
-                            .add(egui::Button::new(
-                                egui::RichText::new($symbol).size(self.cfg.button_size),
-                            ))
-                            .on_hover_text_at_pointer($hover_text);
-                        crate::utils::egui::clickable_url(button, link);
-                    }
-                };
-            }
-            maybe_show_simple_button!(self.homepage(), "\u{e80b}", "Homepage");
-            maybe_show_simple_button!(self.article_pdf(), "\u{e415}", "Article (PDF)");
-            maybe_show_simple_button!(self.article_html(), "\u{eb7e}", "Article (HTML)");
-            maybe_show_simple_button!(self.video(), "\u{f06a}", "Video summary");
-            maybe_show_simple_button!(self.presentation(), "\u{eaf0}", "Full presentation");
-            maybe_show_simple_button!(self.repository(), "\u{e86f}", "Source code");
+            self.show_link_buttons(ui);
 
             // Separator before the copy button
             ui.add(
@@ -237,140 +219,258 @@ impl BibliographyEntry {
             );
 
             self.show_copy_entry_button(ui);
+            self.show_copy_citation_button(ui);
+            self.show_copy_link_button(ui);
+            self.show_export_button(ui);
         });
     }
 
+    fn show_link_buttons(&self, ui: &mut egui::Ui) {
+        // Simple hyperlink buttons
+        macro_rules! maybe_show_simple_button {
+            ($link:expr, $symbol:expr, $hover_text:expr) => {
+                if let Some(link) = &$link {
+                    let button = ui
+                        .add(egui::Button::new(
+                            egui::RichText::new($symbol).size(self.cfg.button_size),
+                        ))
+                        .on_hover_text_at_pointer($hover_text);
+                    crate::utils::egui::clickable_url(button, link);
+                }
+            };
+        }
+        maybe_show_simple_button!(self.links.homepage, "\u{e80b}", "Homepage");
+        maybe_show_simple_button!(self.links.article_pdf, "\u{e415}", "Article (PDF)");
+        maybe_show_simple_button!(self.links.article_html, "\u{eb7e}", "Article (HTML)");
+        maybe_show_simple_button!(self.links.video, "\u{f06a}", "Video summary");
+        maybe_show_simple_button!(self.links.presentation, "\u{eaf0}", "Full presentation");
+        maybe_show_simple_button!(self.links.repository, "\u{e86f}", "Source code");
+    }
+
     fn show_copy_entry_button(&self, ui: &mut egui::Ui) {
-        let persistent_id = ui.make_persistent_id(self.key.clone());
-        let is_copied =
-            ui.memory_mut(|mem| *mem.data.get_temp_mut_or_default::<bool>(persistent_id));
-
-        let button = ui
-            .add(egui::Button::new(
-                egui::RichText::new(if is_copied { "\u{e5ca}" } else { "\u{e609}" })
-                    .size(self.cfg.button_size),
-            ))
-            .on_hover_text_at_pointer(if is_copied {
-                "Copied!"
-            } else {
-                "Copy BibTeX entry"
-            });
+        crate::utils::egui::copy_button(
+            ui,
+            (&self.key, "bibtex"),
+            "\u{e609}",
+            "Copy BibTeX entry",
+            self.cfg.button_size,
+            || self.bibtex.to_string(),
+        );
+    }
 
-        if button.clicked() {
-            // Mark the entry as copied
-            ui.memory_mut(|m| *m.data.get_temp_mut_or_default(persistent_id) = true);
+    fn show_copy_citation_button(&self, ui: &mut egui::Ui) {
+        crate::utils::egui::copy_menu_button(
+            ui,
+            (&self.key, "citation"),
+            "\u{e244}",
+            "Copy citation",
+            self.cfg.button_size,
+            |ui| {
+                let mut citation = None;
+                for &style in CitationStyle::ALL {
+                    if ui.button(style.title()).clicked() {
+                        citation = Some(self.cite(style));
+                    }
+                }
+                citation
+            },
+        );
+    }
 
-            // Create a clone of the entry without custom fields
-            let mut raw_entry = self.entry.clone();
-            for field in CUSTOM_BIB_FIELDS {
-                raw_entry.remove(field);
-            }
+    fn show_copy_link_button(&self, ui: &mut egui::Ui) {
+        crate::utils::egui::copy_button(
+            ui,
+            (&self.key, "link"),
+            "\u{e157}",
+            "Copy link",
+            self.cfg.button_size,
+            || crate::navigation::permalink(&self.target().into()),
+        );
+    }
 
-            // Copy the entry to the clipboard
-            ui.ctx().copy_text(
-                raw_entry
-                    .to_bibtex_string()
-                    .unwrap_or_else(|_| raw_entry.to_biblatex_string()),
-            );
-        } else if is_copied && !button.hovered() {
-            // Unmark the entry as copied
-            ui.memory_mut(|m| *m.data.get_temp_mut_or_default(persistent_id) = false);
-        }
+    fn show_export_button(&self, ui: &mut egui::Ui) {
+        ui.menu_button(
+            egui::RichText::new("\u{f090}").size(self.cfg.button_size),
+            |ui| {
+                for &format in ExportFormat::ALL {
+                    if ui.button(format.title()).clicked() {
+                        crate::utils::download::save_file(
+                            ui.ctx(),
+                            &format!("{}.{}", self.key, format.extension()),
+                            format.mime_type(),
+                            &format.export([&self.entry]),
+                        );
+                        ui.close_menu();
+                    }
+                }
+            },
+        )
+        .response
+        .on_hover_text_at_pointer("Export entry");
     }
 
-    fn maybe_clickable_homepage_or_publication_url(
-        &self,
-        mut response: egui::Response,
-    ) -> egui::Response {
-        if let Ok(homepage) = self.homepage() {
-            response = response
-                .on_hover_cursor(egui::CursorIcon::PointingHand)
-                .on_hover_text_at_pointer("Homepage");
-            crate::utils::egui::clickable_url(response, homepage)
-        } else if let Ok(publication_url) = self.publication_url() {
-            response = response
-                .on_hover_cursor(egui::CursorIcon::PointingHand)
-                .on_hover_text_at_pointer("Publication permalink");
-            crate::utils::egui::clickable_url(response, publication_url)
-        } else {
-            response
+    /// Open the details of the entry when the response is clicked, keeping the query of the
+    /// current route (e.g. the filter of the list).
+    fn clickable_details(&self, response: egui::Response) -> egui::Response {
+        let response = response
+            .on_hover_cursor(egui::CursorIcon::PointingHand)
+            .on_hover_text_at_pointer("Details");
+        if response.clicked() || response.middle_clicked() {
+            let route = crate::navigation::Route {
+                query: crate::navigation::visible_route(&response.ctx).query,
+                ..self.target().into()
+            };
+            crate::navigation::open_route(&response.ctx, route, response.clicked());
         }
+        response
     }
-}
 
-macro_rules! fields {
-    ($($name:ident: $field:expr => $ret:ty),* $(,)*) => {
-        $(::paste::paste! {
-            #[doc = "Get the `" $field "` field."]
-            pub fn $name(&self) -> ::std::result::Result<$ret, ::biblatex::RetrievalError> {
-                ::biblatex::ChunksExt::parse::<$ret>(
-                    self.get($field)
-                        .ok_or_else(|| ::biblatex::RetrievalError::Missing($field.to_string()))?
-                ).map_err(::std::convert::Into::into)
+    /// Show everything that is known about the entry, e.g. within a window.
+    pub fn show_details(&self, ui: &mut egui::Ui) {
+        ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
+            if let Some(thumbnail) = self.loaded_thumbnail(ui) {
+                ui.add(
+                    egui::Image::new(thumbnail.clone())
+                        .rounding(self.cfg.thumbnail_rounding)
+                        .fit_to_exact_size(egui::Vec2::splat(self.cfg.details_thumbnail_size)),
+                );
             }
-        })*
-    };
-}
-macro_rules! alias_fields {
-    ($($name:ident: $field:literal $(| $alias:literal)+ => $ret:ty),* $(,)*) => {
-        $(::paste::paste! {
-            #[doc = "Get the `" $field "` field or fall back on any of its aliases: " $("`" $alias)"`|"+ "`."]
-            pub fn $name(&self) -> ::std::result::Result<$ret, ::biblatex::RetrievalError> {
-                ::biblatex::ChunksExt::parse::<$ret>(
-                self.get($field)
-                    $(.or_else(|| self.get($alias)))+
-                    .ok_or_else(|| ::biblatex::RetrievalError::Missing($field.to_string()))?
-                ).map_err(::std::convert::Into::into)
+            ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
+                crate::utils::egui::strong_heading_sized(
+                    ui,
+                    self.title.as_ref(),
+                    self.cfg.details_title_font_size,
+                );
+                self.show_all_authors(ui);
+                if let Some(venue) = &self.venue {
+                    let venue = match self.date.year {
+                        Some(year) => format!("{venue}, {year}"),
+                        None => venue.to_string(),
+                    };
+                    ui.label(egui::RichText::new(venue).weak());
+                }
+                self.show_keywords(ui);
+            });
+        });
+
+        ui.add_space(self.cfg.details_spacing);
+        ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+            ui.spacing_mut().item_spacing.x = self.cfg.button_spacing;
+            self.show_link_buttons(ui);
+            if let Some(publication_url) = &self.links.publication_url {
+                let button = ui
+                    .button("\u{e89e} Permalink")
+                    .on_hover_text_at_pointer("Publication permalink");
+                crate::utils::egui::clickable_url(button, publication_url);
             }
-        })*
-    };
-}
+        });
 
-impl BibliographyEntry {
-    pub fn year(&self) -> Result<i32, biblatex::RetrievalError> {
-        match self.date()? {
-            biblatex::PermissiveType::Typed(date) => match date.value {
-                biblatex::DateValue::At(date)
-                | biblatex::DateValue::After(date)
-                | biblatex::DateValue::Before(date)
-                | biblatex::DateValue::Between(date, _) => Ok(date.year),
-            },
-            biblatex::PermissiveType::Chunks(_chunks) => unimplemented!(),
+        if let Some(summary) = &self.summary {
+            self.show_details_heading(ui, "Abstract");
+            ui.label(summary.as_ref());
         }
+
+        self.show_videos(ui);
+
+        self.show_details_heading(ui, "BibTeX");
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                self.show_copy_entry_button(ui);
+                ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
+                    let theme = egui_extras::syntax_highlighting::CodeTheme::from_memory(
+                        ui.ctx(),
+                        ui.style(),
+                    );
+                    egui_extras::syntax_highlighting::code_view_ui(
+                        ui,
+                        &theme,
+                        self.bibtex.trim_end(),
+                        "bib",
+                    );
+                });
+            });
+        });
     }
 
-    fields! {
-        title: "title" => String,
+    fn show_details_heading(&self, ui: &mut egui::Ui, heading: &str) {
+        ui.add_space(self.cfg.details_spacing);
+        crate::utils::egui::strong_heading_sized(ui, heading, self.cfg.details_heading_font_size);
     }
 
-    alias_fields! {
-        // Alias that covers all possible fields for publication type/venue/journal
-        venue: "booktitle" | "type" | "journal" | "journaltitle" => String,
-        // Custom fields
-        homepage: "homepage" | "website" => String,
-        publication_url: "publication_url" | "url" => String,
-        article_pdf: "article" | "article_pdf" | "pdf" => String,
-        article_html: "article_html" | "html" => String,
-        video: "video" | "short_video" => String,
-        presentation: "presentation" | "video_presentation" => String,
-        repository: "repository" | "code" => String,
+    /// Full names of all authors, with the site author highlighted.
+    fn show_all_authors(&self, ui: &mut egui::Ui) {
+        let site_author = &crate::site().author;
+        let highlighted_name_index = self.author_index(&site_author.initials, &site_author.surname);
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing.x = 0.0;
+            for (index, author) in self.authors.iter().enumerate() {
+                let separator = if index + 1 < self.authors.len() {
+                    ", "
+                } else {
+                    ""
+                };
+                let name = egui::RichText::new(format!("{}{separator}", author.full_name()));
+                ui.label(if highlighted_name_index == Some(index) {
+                    name.strong()
+                } else {
+                    name
+                });
+            }
+        });
+    }
+
+    fn show_keywords(&self, ui: &mut egui::Ui) {
+        if self.keywords.is_empty() {
+            return;
+        }
+        ui.horizontal_wrapped(|ui| {
+            for keyword in self.keywords.iter() {
+                egui::Frame::none()
+                    .fill(ui.visuals().faint_bg_color)
+                    .rounding(self.cfg.thumbnail_rounding)
+                    .inner_margin(egui::Margin::symmetric(6.0, 2.0))
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new(keyword.as_ref()).small())
+                    });
+            }
+        });
     }
-}
 
-const CUSTOM_BIB_FIELDS: &[&str] = &[
-    "homepage",
-    "website",
-    "publication_url",
-    "url",
-    "article",
-    "article_pdf",
-    "pdf",
-    "article_html",
-    "html",
-    "video",
-    "short_video",
-    "presentation",
-    "video_presentation",
-    "repository",
-    "code",
-];
+    /// Previews of the videos that open them when clicked, or plain links for videos without a
+    /// preview.
+    fn show_videos(&self, ui: &mut egui::Ui) {
+        if self.videos().next().is_none() {
+            return;
+        }
+        self.show_details_heading(ui, "Videos");
+        ui.horizontal_wrapped(|ui| {
+            for (title, url) in self.videos() {
+                ui.vertical(|ui| {
+                    let preview = crate::content::youtube_thumbnail(url).filter(|uri| {
+                        ui.ctx()
+                            .try_load_image(uri, egui::SizeHint::default())
+                            .is_ok()
+                    });
+                    let response = match preview {
+                        Some(uri) => {
+                            let width = self.cfg.video_preview_width;
+                            let response = ui.add(
+                                egui::Image::from_uri(uri)
+                                    .sense(egui::Sense::click())
+                                    .rounding(self.cfg.thumbnail_rounding)
+                                    .fit_to_exact_size(egui::vec2(width, width * 9.0 / 16.0)),
+                            );
+                            ui.label(egui::RichText::new(title).weak());
+                            response
+                        }
+                        None => ui.link(title),
+                    };
+                    crate::utils::egui::clickable_url(
+                        response.on_hover_cursor(egui::CursorIcon::PointingHand),
+                        url,
+                    );
+                });
+            }
+        });
+    }
+}
@@ -1,4 +1,4 @@
-pub use bibliography::Bibliography;
+pub use bibliography::{Bibliography, DetailsWindow};
 pub use entry::{BibliographyEntry, BibliographyEntryConfig};
 
 mod bibliography;
@@ -1,3 +1,3 @@
-pub use page::TeachingPage;
+pub use page::PAGE;
 
 mod page;
@@ -1,4 +1,30 @@
-const PAGE: crate::page::Page = crate::page::Page::Teaching;
+use crate::utils::{loading::Loadable, markdown::MarkdownDocument};
+
+pub const PAGE: crate::page::Page = crate::page::Page::new(&Descriptor);
+
+struct Descriptor;
+
+impl crate::page::PageDescriptor for Descriptor {
+    fn slug(&self) -> &'static str {
+        "teaching"
+    }
+
+    fn title(&self) -> &'static str {
+        "Teaching"
+    }
+
+    fn description(&self) -> &'static str {
+        "Teaching materials"
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{e80c}"
+    }
+
+    fn create(&self) -> Box<dyn eframe::App> {
+        Box::<TeachingPage>::default()
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
 pub struct TeachingPageConfig {
@@ -17,10 +43,18 @@ impl Default for TeachingPageConfig {
 }
 
 #[must_use = "You should call .update()"]
-#[derive(Default)]
 pub struct TeachingPage {
     pub cfg: TeachingPageConfig,
-    commonmark_cache: egui_commonmark::CommonMarkCache,
+    rmins: Loadable<MarkdownDocument>,
+}
+
+impl Default for TeachingPage {
+    fn default() -> Self {
+        Self {
+            cfg: TeachingPageConfig::default(),
+            rmins: crate::macros::load_markdown!("teaching/rmins.md"),
+        }
+    }
 }
 
 impl eframe::App for TeachingPage {
@@ -67,11 +101,7 @@ impl TeachingPage {
 
         ui.add_space(1.0 * ui.spacing().item_spacing.y);
 
-        egui_commonmark::commonmark_str!(
-            ui,
-            &mut self.commonmark_cache,
-            "content/teaching/rmins.md"
-        );
+        self.rmins.show(ui, |ui, rmins| rmins.show(ui));
 
         ui.add_space(2.0 * ui.spacing().item_spacing.y);
 
@@ -0,0 +1,177 @@
+//! State of the app that is persisted between visits.
+//!
+//! The state is stored together with its version. State of an older version is migrated to the
+//! current one, so that returning visitors keep their preferences whenever a field is added.
+//! State that cannot be restored is logged and replaced by the default.
+
+use std::collections::BTreeSet;
+
+/// Version of [`PersistedState`], which must be bumped (with a new migration) whenever the
+/// shape of the state changes.
+const VERSION: u32 = 2;
+
+/// Preferences of the visitor and their place in the app.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct PersistedState {
+    version: u32,
+    pub theme: egui::Theme,
+    /// Route that was shown last, e.g. `research/publications`
+    pub last_route: Option<String>,
+    /// Zoom factor of the UI
+    pub ui_scale: f32,
+    /// Identifiers of the banners that were dismissed
+    pub dismissed_banners: BTreeSet<String>,
+    /// Last scroll offset of each visited route
+    pub scroll_offsets: rustc_hash::FxHashMap<String, f32>,
+}
+
+impl Default for PersistedState {
+    fn default() -> Self {
+        Self {
+            version: VERSION,
+            theme: egui::Theme::Dark,
+            last_route: None,
+            ui_scale: 1.0,
+            dismissed_banners: BTreeSet::new(),
+            scroll_offsets: rustc_hash::FxHashMap::default(),
+        }
+    }
+}
+
+/// Reason why the persisted state could not be restored.
+#[derive(Debug)]
+pub enum RestoreError {
+    /// The stored state is not valid for its version
+    Malformed(ron::error::SpannedError),
+    /// The state was stored by a newer version of the app
+    UnsupportedVersion(u32),
+}
+
+impl std::fmt::Display for RestoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Malformed(err) => write!(f, "{err}"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "version {version} is newer than the supported version {VERSION}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RestoreError {}
+
+impl From<ron::error::SpannedError> for RestoreError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        Self::Malformed(err)
+    }
+}
+
+impl PersistedState {
+    /// Restore the state from the storage, or use the default state if there is none.
+    pub fn restore(storage: Option<&dyn eframe::Storage>) -> Self {
+        let Some(src) = storage.and_then(|storage| storage.get_string(eframe::APP_KEY)) else {
+            return Self::default();
+        };
+        Self::parse(&src).unwrap_or_else(|err| {
+            log::warn!("Failed to restore the app state, so the defaults are used: {err}");
+            Self::default()
+        })
+    }
+
+    pub fn save(&self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, eframe::APP_KEY, self);
+    }
+
+    /// Parse the state of any version, migrating it to the current one.
+    fn parse(src: &str) -> Result<Self, RestoreError> {
+        match ron::from_str::<Header>(src)?.version {
+            1 => Ok(migrate_v1(ron::from_str(src)?)),
+            VERSION => Ok(ron::from_str(src)?),
+            version => Err(RestoreError::UnsupportedVersion(version)),
+        }
+    }
+}
+
+/// Version of the stored state, while all other fields are ignored.
+#[derive(serde::Deserialize)]
+struct Header {
+    /// The first version was stored without it
+    #[serde(default = "first_version")]
+    version: u32,
+}
+
+fn first_version() -> u32 {
+    1
+}
+
+/// State that was stored before it was versioned, which only kept the theme and the scroll
+/// offsets of the navigator.
+mod v1 {
+    #[derive(serde::Deserialize)]
+    #[serde(default)]
+    pub struct State {
+        pub theme: egui::Theme,
+        pub navigator: Navigator,
+    }
+
+    impl Default for State {
+        fn default() -> Self {
+            Self {
+                theme: egui::Theme::Dark,
+                navigator: Navigator::default(),
+            }
+        }
+    }
+
+    #[derive(Default, serde::Deserialize)]
+    #[serde(default)]
+    pub struct Navigator {
+        pub scroll_offsets: rustc_hash::FxHashMap<String, f32>,
+    }
+}
+
+fn migrate_v1(state: v1::State) -> PersistedState {
+    PersistedState {
+        theme: state.theme,
+        scroll_offsets: state.navigator.scroll_offsets,
+        ..PersistedState::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrate_persisted_state() {
+        // State stored by the app before it was versioned
+        let state = PersistedState::parse(
+            r#"(theme:Light,navigator:(scroll_offsets:{"research/publications":120.0}))"#,
+        )
+        .unwrap();
+        assert_eq!(state.version, VERSION);
+        assert_eq!(state.theme, egui::Theme::Light);
+        assert_eq!(state.scroll_offsets["research/publications"], 120.0);
+        assert_eq!(state.ui_scale, 1.0);
+
+        let state = PersistedState {
+            last_route: Some("research/publications".to_owned()),
+            ui_scale: 1.25,
+            dismissed_banners: BTreeSet::from(["welcome".to_owned()]),
+            ..state
+        };
+        let src = ron::to_string(&state).unwrap();
+        assert_eq!(PersistedState::parse(&src).unwrap(), state);
+
+        assert!(matches!(
+            PersistedState::parse("(version:99)"),
+            Err(RestoreError::UnsupportedVersion(99))
+        ));
+        assert!(matches!(
+            PersistedState::parse("(version:2,theme:Blue)"),
+            Err(RestoreError::Malformed(_))
+        ));
+    }
+}
@@ -0,0 +1,69 @@
+//! Schema of `content/site.yaml`, which is shared with the build script.
+//!
+//! All text is stored as [`Cow`] so that the build script can generate the configuration as
+//! static data.
+
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SiteConfig {
+    pub author: AuthorConfig,
+    pub pages: PagesConfig,
+    /// Social links in the order in which they are shown
+    #[serde(default)]
+    pub socials: Cow<'static, [SocialLink]>,
+    /// Announcements shown above the pages until the visitor dismisses them
+    #[serde(default)]
+    pub banners: Cow<'static, [Banner]>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthorConfig {
+    pub name: Cow<'static, str>,
+    /// Name that is highlighted among the authors of publications
+    pub short_name: Cow<'static, str>,
+    pub surname: Cow<'static, str>,
+    pub initials: Cow<'static, str>,
+    pub title: Cow<'static, str>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PagesConfig {
+    /// Slugs of the shown pages in the order of the navigation panel
+    pub enabled: Cow<'static, [Cow<'static, str>]>,
+    /// Slugs of the pages that are separated from the preceding ones in the navigation panel
+    #[serde(default)]
+    pub separate_menu_at: Cow<'static, [Cow<'static, str>]>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SocialLink {
+    pub kind: Social,
+    pub url: Cow<'static, str>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, serde::Deserialize, strum::EnumIter)]
+#[serde(rename_all = "lowercase")]
+pub enum Social {
+    Email,
+    LinkedIn,
+    Orcid,
+    Scholar,
+    YouTube,
+    GitHub,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Banner {
+    /// Identifier that is remembered once the banner is dismissed (changing it shows the banner
+    /// again)
+    pub id: Cow<'static, str>,
+    pub text: Cow<'static, str>,
+    #[serde(default)]
+    pub url: Option<Cow<'static, str>>,
+}
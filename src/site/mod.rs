@@ -0,0 +1,146 @@
+//! Configuration of the site that is embedded from `content/site.yaml`.
+//!
+//! The configuration is validated and compiled into static data by the build script, which
+//! shares the [`config`] and `validate` modules, so an invalid configuration fails the build with
+//! the location of the error.
+
+pub use config::{SiteConfig, Social, SocialLink};
+
+mod config;
+#[cfg(test)]
+mod validate;
+
+use std::borrow::Cow;
+
+// The generated configuration only names the types of the sections that are not empty
+#[allow(unused_imports)]
+use config::{AuthorConfig, Banner, PagesConfig};
+
+use crate::page::Page;
+
+include!(concat!(env!("OUT_DIR"), "/site.rs"));
+
+/// Configuration of the site from `content/site.yaml`.
+pub fn site() -> &'static SiteConfig {
+    &SITE
+}
+
+impl SiteConfig {
+    /// Enabled pages in the order in which they are listed, skipping those excluded by their
+    /// cargo feature.
+    pub fn enabled_pages(&self) -> Vec<Page> {
+        self.pages
+            .enabled
+            .iter()
+            .filter_map(|slug| {
+                crate::page::PAGES
+                    .iter()
+                    .copied()
+                    .find(|page| page.slug() == *slug)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn enabled_slugs(cfg: &SiteConfig) -> Vec<&'static str> {
+        cfg.enabled_pages().iter().map(|page| page.slug()).collect()
+    }
+
+    #[test]
+    fn parse_profiles() {
+        let cfg = SiteConfig::parse(crate::macros::include_content_str!("site.yaml")).unwrap();
+        assert_eq!(cfg, *site());
+        assert_eq!(
+            enabled_slugs(&cfg),
+            [
+                "about",
+                #[cfg(feature = "page-research")]
+                "research",
+                #[cfg(feature = "page-teaching")]
+                "teaching",
+                #[cfg(feature = "page-cv")]
+                "cv",
+            ]
+        );
+
+        let cfg =
+            SiteConfig::parse(crate::macros::include_content_str!("examples/site.yaml")).unwrap();
+        assert_eq!(cfg.author.name, "Jane Doe");
+        assert_eq!(
+            enabled_slugs(&cfg),
+            [
+                "about",
+                #[cfg(feature = "page-blog")]
+                "blog",
+                #[cfg(feature = "page-research")]
+                "research",
+            ]
+        );
+        assert_eq!(
+            cfg.socials.iter().map(|social| social.kind).collect_vec(),
+            [Social::GitHub, Social::Email]
+        );
+        assert_eq!(
+            cfg.banners.iter().map(|banner| &banner.id).collect_vec(),
+            ["open-positions"]
+        );
+    }
+
+    #[test]
+    fn known_slugs() {
+        // The build script validates the configuration against all slugs
+        assert_eq!(
+            crate::page::PAGES
+                .iter()
+                .map(|page| page.slug())
+                .chain(crate::page::EXCLUDED_PAGES.iter().copied())
+                .sorted()
+                .collect_vec(),
+            validate::PAGE_SLUGS
+        );
+    }
+
+    #[test]
+    fn reject_invalid_config() {
+        let src = crate::macros::include_content_str!("examples/site.yaml");
+        for (valid, invalid) in [
+            ("[about, blog, research]", "[about, unknown]"),
+            ("[about, blog, research]", "[about, about]"),
+            ("separate_menu_at: []", "separate_menu_at: [cv]"),
+            ("kind: email", "kind: github"),
+            ("kind: email", "kind: fax"),
+            (
+                "id: open-positions",
+                "id: open-positions\n    text: Twice\n  - id: open-positions",
+            ),
+        ] {
+            assert!(SiteConfig::parse(&src.replace(valid, invalid)).is_err());
+        }
+
+        // Errors point to the offending value
+        let err = SiteConfig::parse(&src.replace("[about, blog, research]", "[about, unknown]"))
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (11, 20));
+        assert_eq!(err.message, "the enabled page `unknown` does not exist");
+        let err = SiteConfig::parse(&src.replace("kind: email", "kind: github")).unwrap_err();
+        assert_eq!((err.line, err.column), (17, 5));
+
+        // A duplicate is reported where it is enabled, even if it occurs again later
+        let err = SiteConfig::parse(
+            &src.replace("[about, blog, research]", "[about, cv, cv]")
+                .replace("separate_menu_at: []", "separate_menu_at: [cv]"),
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (11, 24));
+        assert_eq!(err.message, "the page `cv` is enabled more than once");
+        let err =
+            SiteConfig::parse(&src.replace("initials: \"JD\"", "initials: \"\"")).unwrap_err();
+        assert_eq!((err.line, err.column), (7, 3));
+    }
+}
@@ -0,0 +1,185 @@
+//! Validation of `content/site.yaml`, which is shared with the build script that rejects an
+//! invalid configuration (the app embeds the configuration that the build script generated).
+
+use itertools::Itertools;
+
+use super::config::SiteConfig;
+
+/// Slugs of all pages, including those that are excluded by their cargo feature.
+pub const PAGE_SLUGS: &[&str] = &[
+    "about", "blog", "cv", "demos", "projects", "research", "teaching",
+];
+
+/// Invalid configuration with the location of the error (both line and column start at one).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl ConfigError {
+    /// Error at the last occurrence of the value in the section of the key (e.g. the second one
+    /// of a duplicate), at the key itself if there is no value, or at the start of the source if
+    /// neither occurs.
+    fn at_value(src: &str, key: &str, value: Option<&str>, message: String) -> Self {
+        let offset = key_section(src, key).and_then(|(start, end)| match value {
+            Some(value) => src[start..end]
+                .match_indices(value)
+                .filter(|&(index, _)| is_word(&src[start..end], index, value.len()))
+                .last()
+                .map(|(index, _)| start + index),
+            None => Some(start),
+        });
+        let before = &src[..offset.unwrap_or(0)];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message,
+        }
+    }
+}
+
+/// Byte range from the first occurrence of the key up to the next line that is not indented
+/// deeper (sequence items may share the indentation of their key).
+fn key_section(src: &str, key: &str) -> Option<(usize, usize)> {
+    let mut lines = src.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, line))
+    });
+    let (start, indent) = lines.by_ref().find_map(|(start, line)| {
+        let content = line.trim_start();
+        let indent = line.len() - content.len();
+        (content.strip_prefix(key)?.starts_with(':')).then_some((start + indent, indent))
+    })?;
+    let end = lines
+        .find(|(_, line)| {
+            let content = line.trim_start();
+            let is_nested = content.is_empty()
+                || content.starts_with('#')
+                || line.len() - content.len() > indent
+                || content.starts_with('-');
+            !is_nested
+        })
+        .map_or(src.len(), |(start, _)| start);
+    Some((start, end))
+}
+
+/// Whether the match at the index is not part of a longer word (e.g. `cv` in `cv-old`).
+fn is_word(src: &str, index: usize, len: usize) -> bool {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+    !src[..index].chars().next_back().is_some_and(is_word_char)
+        && !src[index + len..].chars().next().is_some_and(is_word_char)
+}
+
+impl SiteConfig {
+    /// Parse and validate the configuration.
+    pub fn parse(src: &str) -> Result<Self, ConfigError> {
+        let cfg: Self = serde_yaml::from_str(src).map_err(|err| {
+            let (line, column) = err
+                .location()
+                .map_or((1, 1), |location| (location.line(), location.column()));
+            // The location is already part of the error, so it is not repeated in the message
+            let message = err.to_string();
+            let message = message
+                .split_once(" at line ")
+                .map_or(message.as_str(), |(message, _)| message);
+            ConfigError {
+                line,
+                column,
+                message: message.to_owned(),
+            }
+        })?;
+        cfg.validate().map_err(|(key, value, message)| {
+            ConfigError::at_value(src, key, value.as_deref(), message)
+        })?;
+        Ok(cfg)
+    }
+
+    /// Check the configuration, or return the key of the offending section, the offending value
+    /// (if the key itself is not the culprit) and a description of the error.
+    fn validate(&self) -> Result<(), (&'static str, Option<String>, String)> {
+        if self.author.initials.is_empty() {
+            return Err((
+                "initials",
+                None,
+                "the initials of the author are empty".to_owned(),
+            ));
+        }
+
+        if self.pages.enabled.is_empty() {
+            return Err(("enabled", None, "no page is enabled".to_owned()));
+        }
+        if let Some(slug) = self
+            .pages
+            .enabled
+            .iter()
+            .find(|slug| !PAGE_SLUGS.contains(&slug.as_ref()))
+        {
+            return Err((
+                "enabled",
+                Some(slug.to_string()),
+                format!("the enabled page `{slug}` does not exist"),
+            ));
+        }
+        if let Some(slug) = self.pages.enabled.iter().duplicates().next() {
+            return Err((
+                "enabled",
+                Some(slug.to_string()),
+                format!("the page `{slug}` is enabled more than once"),
+            ));
+        }
+        if let Some(slug) = self
+            .pages
+            .separate_menu_at
+            .iter()
+            .find(|slug| !self.pages.enabled.contains(slug))
+        {
+            return Err((
+                "separate_menu_at",
+                Some(slug.to_string()),
+                format!("the separated page `{slug}` is not enabled"),
+            ));
+        }
+
+        if let Some(social) = self
+            .socials
+            .iter()
+            .map(|social| social.kind)
+            .duplicates()
+            .next()
+        {
+            return Err((
+                "socials",
+                Some(format!("kind: {social:?}").to_lowercase()),
+                format!("the social link `{social:?}` is listed more than once"),
+            ));
+        }
+
+        if let Some(id) = self
+            .banners
+            .iter()
+            .map(|banner| &banner.id)
+            .duplicates()
+            .next()
+        {
+            return Err((
+                "banners",
+                Some(id.to_string()),
+                format!("the banner `{id}` is listed more than once"),
+            ));
+        }
+
+        Ok(())
+    }
+}
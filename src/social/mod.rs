@@ -1,33 +1,27 @@
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, strum::EnumIter)]
-pub enum Social {
-    Email,
-    LinkedIn,
-    Orcid,
-    Scholar,
-    YouTube,
-    GitHub,
-}
+use crate::site::Social;
 
-impl Social {
-    pub fn show(self, ui: &mut egui::Ui) {
+impl crate::site::SocialLink {
+    pub fn show(&self, ui: &mut egui::Ui) {
         let button = ui
-            .add(egui::Button::new(self.icon()))
-            .on_hover_text(self.description());
+            .add(egui::Button::new(self.kind.icon()))
+            .on_hover_text(self.kind.description());
 
         if button.clicked() {
-            ui.ctx().open_url(egui::OpenUrl::same_tab(self.url()));
+            ui.ctx().open_url(egui::OpenUrl::same_tab(&self.url));
         } else if button.middle_clicked() {
-            match self {
-                Self::Email => {
-                    ui.ctx().open_url(egui::OpenUrl::same_tab(self.url()));
+            match self.kind {
+                Social::Email => {
+                    ui.ctx().open_url(egui::OpenUrl::same_tab(&self.url));
                 }
                 _ => {
-                    ui.ctx().open_url(egui::OpenUrl::new_tab(self.url()));
+                    ui.ctx().open_url(egui::OpenUrl::new_tab(&self.url));
                 }
             }
         }
     }
+}
 
+impl Social {
     pub fn description(&self) -> &str {
         match self {
             Self::Email => "Write me an email",
@@ -39,17 +33,6 @@ impl Social {
         }
     }
 
-    pub fn url(&self) -> &str {
-        match self {
-            Self::Email => crate::EMAIL_ADDRESS,
-            Self::LinkedIn => crate::LINKEDIN_URL,
-            Self::Orcid => crate::ORCID_URL,
-            Self::Scholar => crate::SCHOLAR_URL,
-            Self::YouTube => crate::YOUTUBE_URL,
-            Self::GitHub => crate::GITHUB_URL,
-        }
-    }
-
     pub fn icon(&self) -> &str {
         match self {
             Self::Email => "@",
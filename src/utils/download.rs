@@ -0,0 +1,164 @@
+//! Saving of files that are generated by the app, e.g. exported publications.
+//!
+//! The web app lets the browser download the file, while the native app writes it to the
+//! downloads directory of the user (or the working directory if there is none) without
+//! overwriting existing files. The outcome is shown in an overlay via [`show_status`].
+
+/// Seconds for which the outcome of the last save is shown.
+const STATUS_SECS: f64 = 4.0;
+
+/// Outcome of the last save, i.e. the location of the file or the error.
+#[derive(Debug, Clone)]
+struct Status {
+    file_name: String,
+    outcome: Result<String, String>,
+    time: f64,
+}
+
+fn status_id() -> egui::Id {
+    egui::Id::new("download_status")
+}
+
+/// Save a file with the given name and contents, and let the visitor know whether it succeeded.
+pub fn save_file(ctx: &egui::Context, file_name: &str, mime_type: &str, contents: &str) {
+    let outcome = save(file_name, mime_type, contents);
+    match &outcome {
+        Ok(location) => log::info!("Saved {location}"),
+        Err(err) => log::warn!("Failed to save {file_name}: {err}"),
+    }
+    let status = Status {
+        file_name: file_name.to_owned(),
+        outcome,
+        time: ctx.input(|i| i.time),
+    };
+    ctx.data_mut(|data| data.insert_temp(status_id(), status));
+}
+
+/// Show the outcome of the last save in an overlay for a few seconds.
+pub fn show_status(ctx: &egui::Context) {
+    let Some(status) = ctx.data(|data| data.get_temp::<Status>(status_id())) else {
+        return;
+    };
+    let elapsed = ctx.input(|i| i.time) - status.time;
+    if elapsed >= STATUS_SECS {
+        ctx.data_mut(|data| data.remove::<Status>(status_id()));
+        return;
+    }
+    ctx.request_repaint_after(std::time::Duration::from_secs_f64(STATUS_SECS - elapsed));
+
+    egui::Area::new(status_id())
+        .order(egui::Order::Foreground)
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -48.0))
+        .show(ctx, |ui| match &status.outcome {
+            Ok(location) => {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.label(format!("\u{e5ca} Saved {location}"));
+                });
+            }
+            Err(err) => {
+                super::egui::error_card(ui, format!("Failed to save {}", status.file_name), |ui| {
+                    ui.monospace(err);
+                });
+            }
+        });
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save(file_name: &str, mime_type: &str, contents: &str) -> Result<String, String> {
+    use eframe::wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+    /// Milliseconds after which the object URL is released, once the browser has started the
+    /// download.
+    const REVOKE_DELAY_MS: i32 = 10_000;
+
+    let describe = |err: JsValue| format!("{err:?}");
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(
+        &web_sys::js_sys::Array::of1(&JsValue::from_str(contents)),
+        &options,
+    )
+    .map_err(describe)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(describe)?;
+
+    // Clicking a link with the `download` attribute saves its target instead of opening it
+    let window = web_sys::window().ok_or("There is no window")?;
+    let anchor = window
+        .document()
+        .ok_or("There is no document")?
+        .create_element("a")
+        .map_err(describe)?
+        .dyn_into::<web_sys::HtmlAnchorElement>()
+        .map_err(|element| describe(element.into()))?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    // The download may start asynchronously, so the URL must remain valid for a while
+    let revoke = Closure::once_into_js(move || {
+        web_sys::Url::revoke_object_url(&url).ok();
+    });
+    window
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            revoke.unchecked_ref(),
+            REVOKE_DELAY_MS,
+        )
+        .map_err(describe)?;
+    Ok(file_name.to_owned())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn save(file_name: &str, _mime_type: &str, contents: &str) -> Result<String, String> {
+    use std::io::Write as _;
+
+    let dir = ["HOME", "USERPROFILE"]
+        .iter()
+        .find_map(std::env::var_os)
+        .map(|home| std::path::PathBuf::from(home).join("Downloads"))
+        .filter(|dir| dir.is_dir())
+        .unwrap_or_default();
+
+    // Existing files are kept, and the new one is numbered instead, e.g. `bibliography (1).bib`
+    for copy in 0.. {
+        let path = dir.join(numbered(file_name, copy));
+        match std::fs::File::create_new(&path) {
+            Ok(mut file) => {
+                file.write_all(contents.as_bytes())
+                    .map_err(|err| err.to_string())?;
+                return Ok(path.display().to_string());
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(err) => return Err(err.to_string()),
+        }
+    }
+    unreachable!("There are fewer files than numbers")
+}
+
+/// Name of the given copy of the file, e.g. `bibliography (2).bib` for the second copy.
+#[cfg(not(target_arch = "wasm32"))]
+fn numbered(file_name: &str, copy: usize) -> String {
+    if copy == 0 {
+        return file_name.to_owned();
+    }
+    match file_name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => format!("{stem} ({copy}).{extension}"),
+        _ => format!("{file_name} ({copy})"),
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_copies() {
+        assert_eq!(numbered("bibliography.bib", 0), "bibliography.bib");
+        assert_eq!(numbered("bibliography.bib", 2), "bibliography (2).bib");
+        assert_eq!(
+            numbered("publications.csl.json", 1),
+            "publications.csl (1).json"
+        );
+        assert_eq!(numbered("README", 1), "README (1)");
+        assert_eq!(numbered(".bib", 1), ".bib (1)");
+    }
+}
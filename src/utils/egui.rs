@@ -26,8 +26,18 @@ impl ScrollableFramedCentralPanel {
         debug_assert!(self.min_inner_margin.bottom.is_sign_positive());
 
         egui::CentralPanel::default().show(ctx, |ui| {
-            egui::ScrollArea::vertical()
-                .show(ui, |ui| {
+            // Fade and slide the content while transitioning between pages
+            let transition = crate::navigation::transition_style(ctx);
+            if let Some(transition) = transition {
+                ui.multiply_opacity(transition.opacity);
+            }
+            let transform = egui::emath::TSTransform::from_translation(egui::vec2(
+                transition.map_or(0.0, |transition| transition.offset),
+                0.0,
+            ));
+
+            ui.with_visual_transform(transform, |ui| {
+                let output = crate::navigation::scroll_area(ctx).show(ui, |ui| {
                     let margin_x =
                         (ctx.screen_rect().width() - self.max_content_width).max(0.0) / 2.0;
                     let inner_margin = egui::Margin {
@@ -39,25 +49,15 @@ impl ScrollableFramedCentralPanel {
                         .inner_margin(inner_margin)
                         .show(ui, |ui| add_contents(ui))
                         .inner
-                })
-                .inner
+                });
+                crate::navigation::remember_scroll_offset(ctx, &output);
+                output.inner
+            })
+            .inner
         })
     }
 }
 
-pub fn open_url_on_page(ctx: &egui::Context, page: crate::page::Page, same_tab: bool) {
-    let target_url = if let Some(url) = page.redirect_page() {
-        url.to_owned()
-    } else {
-        format!("#{}", page.to_string().to_lowercase())
-    };
-    ctx.open_url(if same_tab {
-        egui::OpenUrl::same_tab(target_url)
-    } else {
-        egui::OpenUrl::new_tab(target_url)
-    });
-}
-
 pub fn clickable_url(response: egui::Response, url: impl ToString) -> egui::Response {
     debug_assert!(response.sense.click);
 
@@ -69,10 +69,102 @@ pub fn clickable_url(response: egui::Response, url: impl ToString) -> egui::Resp
     response
 }
 
+/// Seconds for which a copy button keeps showing that its text was copied once it is no longer
+/// hovered.
+const COPIED_FEEDBACK_SECS: f64 = 1.5;
+
+/// Button that copies the text to the clipboard, which shows a check mark in place of its symbol
+/// until shortly after it is no longer hovered.
+pub fn copy_button(
+    ui: &mut egui::Ui,
+    id_salt: impl std::hash::Hash,
+    symbol: &str,
+    hover_text: &str,
+    size: f32,
+    text: impl FnOnce() -> String,
+) -> egui::Response {
+    let persistent_id = ui.make_persistent_id(id_salt);
+    let is_copied = is_copied(ui, persistent_id);
+
+    let button = ui
+        .add(egui::Button::new(
+            egui::RichText::new(if is_copied { "\u{e5ca}" } else { symbol }).size(size),
+        ))
+        .on_hover_text_at_pointer(if is_copied { "Copied!" } else { hover_text });
+
+    let copied = button.clicked().then(text);
+    update_copied(ui, persistent_id, &button, copied);
+    button
+}
+
+/// Menu button that copies the text returned by the menu to the clipboard, with the same
+/// feedback as [`copy_button`].
+#[cfg(feature = "bibliography")]
+pub fn copy_menu_button(
+    ui: &mut egui::Ui,
+    id_salt: impl std::hash::Hash,
+    symbol: &str,
+    hover_text: &str,
+    size: f32,
+    add_contents: impl FnOnce(&mut egui::Ui) -> Option<String>,
+) -> egui::Response {
+    let persistent_id = ui.make_persistent_id(id_salt);
+    let is_copied = is_copied(ui, persistent_id);
+
+    let menu = ui.menu_button(
+        egui::RichText::new(if is_copied { "\u{e5ca}" } else { symbol }).size(size),
+        |ui| {
+            let text = add_contents(ui);
+            if text.is_some() {
+                ui.close_menu();
+            }
+            text
+        },
+    );
+    let button =
+        menu.response
+            .on_hover_text_at_pointer(if is_copied { "Copied!" } else { hover_text });
+
+    update_copied(ui, persistent_id, &button, menu.inner.flatten());
+    button
+}
+
+fn is_copied(ui: &egui::Ui, id: egui::Id) -> bool {
+    ui.data(|data| data.get_temp::<f64>(id)).is_some()
+}
+
+/// Copy the text to the clipboard if any, or stop showing the feedback of an earlier copy once
+/// the button has not been hovered for a while.
+fn update_copied(ui: &egui::Ui, id: egui::Id, button: &egui::Response, text: Option<String>) {
+    let now = ui.input(|i| i.time);
+    if let Some(text) = text {
+        ui.ctx().copy_text(text);
+        ui.data_mut(|data| data.insert_temp(id, now));
+    } else if let Some(copied_at) = ui.data(|data| data.get_temp::<f64>(id)) {
+        if button.hovered() {
+            ui.data_mut(|data| data.insert_temp(id, now));
+        } else if now - copied_at >= COPIED_FEEDBACK_SECS {
+            ui.data_mut(|data| data.remove::<f64>(id));
+        } else {
+            ui.ctx()
+                .request_repaint_after(std::time::Duration::from_secs_f64(
+                    COPIED_FEEDBACK_SECS - (now - copied_at),
+                ));
+        }
+    }
+}
+
 pub fn strong_heading(ui: &mut egui::Ui, text: impl Into<String>) -> egui::Response {
     ui.label(egui::RichText::new(text).heading().strong())
 }
 
+#[cfg(any(
+    feature = "page-blog",
+    feature = "page-demos",
+    feature = "page-projects",
+    feature = "page-research",
+    feature = "page-teaching"
+))]
 pub fn centered_strong_heading(
     ui: &mut egui::Ui,
     text: impl Into<String>,
@@ -82,6 +174,7 @@ pub fn centered_strong_heading(
     })
 }
 
+#[cfg(any(feature = "bibliography", feature = "page-teaching"))]
 pub fn heading_sized(ui: &mut egui::Ui, text: impl Into<String>, size: f32) -> egui::Response {
     ui.label(egui::RichText::new(text).heading().size(size))
 }
@@ -103,3 +196,25 @@ pub fn centered_strong_heading_sized(
         crate::utils::egui::strong_heading_sized(ui, text, size)
     })
 }
+
+/// Card that reports an error, e.g. in place of content which could not be shown.
+#[cfg(any(hot_reload, feature = "bibliography", feature = "remote-content"))]
+pub fn error_card<R>(
+    ui: &mut egui::Ui,
+    title: impl Into<String>,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    let color = ui.visuals().error_fg_color;
+    egui::Frame::group(ui.style())
+        .stroke(egui::Stroke::new(1.0, color))
+        .fill(ui.visuals().extreme_bg_color)
+        .show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.label(
+                egui::RichText::new(format!("\u{e000} {}", title.into()))
+                    .strong()
+                    .color(color),
+            );
+            add_contents(ui)
+        })
+}
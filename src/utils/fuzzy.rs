@@ -0,0 +1,80 @@
+/// Levenshtein edit distance between two strings, ignoring ASCII case.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b
+        .chars()
+        .map(|c| c.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    let mut previous_row = (0..=b.len()).collect::<Vec<_>>();
+    let mut current_row = vec![0; b.len() + 1];
+    for (i, a) in a.chars().map(|c| c.to_ascii_lowercase()).enumerate() {
+        current_row[0] = i + 1;
+        for (j, &b) in b.iter().enumerate() {
+            current_row[j + 1] = (previous_row[j] + usize::from(a != b))
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+    previous_row[b.len()]
+}
+
+/// Select up to `limit` candidates that are similar to the query, closest first.
+///
+/// Each candidate is ranked by the closest of its keys (e.g. its title and its path), and keys
+/// that would require changing more than half of their characters are ignored.
+pub fn closest<T>(
+    query: &str,
+    candidates: impl IntoIterator<Item = T>,
+    keys: impl Fn(&T) -> Vec<&str>,
+    limit: usize,
+) -> Vec<T> {
+    let mut matches = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = keys(&candidate)
+                .into_iter()
+                .filter_map(|key| {
+                    let distance = edit_distance(query, key);
+                    (2 * distance <= key.chars().count().max(query.chars().count()))
+                        .then_some(distance)
+                })
+                .min()?;
+            Some((distance, candidate))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by_key(|(distance, _)| *distance);
+    matches
+        .into_iter()
+        .take(limit)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("research", "Research"), 0);
+        assert_eq!(edit_distance("reserch", "research"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "about"), 5);
+    }
+
+    #[test]
+    fn closest_candidates() {
+        let candidates = ["about", "research", "teaching", "cv"];
+        assert_eq!(closest("reseach", candidates, |c| vec![c], 3), ["research"]);
+        assert_eq!(closest("teachng", candidates, |c| vec![c], 3), ["teaching"]);
+        assert!(closest("xyz", candidates, |c| vec![c], 3).is_empty());
+
+        // The closest key of each candidate counts
+        let candidates = [("Talks", "research/talks"), ("About", "about")];
+        assert_eq!(
+            closest("talk", candidates, |(title, path)| vec![title, path], 3),
+            [("Talks", "research/talks")]
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! Reloading of the content from disk whenever it changes, which is enabled for debug builds of
+//! the native app with the `hot-reload` feature (see `build.rs`).
+//!
+//! Each [`Reload`] re-reads its file after a change and swaps the parsed value into the live
+//! page. Content that fails to parse keeps its previous value, and the error is shown in an
+//! overlay via [`show_errors`] until the file is fixed.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Number of changes in the content directory since the start of the app.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Watcher that is kept alive for the whole lifetime of the app.
+static WATCHER: std::sync::Mutex<Option<notify::RecommendedWatcher>> = std::sync::Mutex::new(None);
+
+/// Location of the content directory in the source tree.
+pub fn content_dir() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/content"))
+}
+
+/// URI of a file on disk that is understood by the image loaders.
+pub fn file_uri(path: &Path) -> String {
+    format!("file://{}", path.display())
+}
+
+/// Start watching the content directory for changes.
+pub fn init(ctx: &egui::Context) {
+    let ctx = ctx.clone();
+    let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        match event {
+            Ok(event) if !event.kind.is_access() => {
+                // Images are reloaded by their loaders on the next request
+                for path in &event.paths {
+                    ctx.forget_image(&file_uri(path));
+                }
+                GENERATION.fetch_add(1, Ordering::Relaxed);
+                ctx.request_repaint();
+            }
+            Ok(_) => {}
+            Err(err) => log::warn!("Failed to watch the content directory: {err}"),
+        }
+    })
+    .and_then(|mut watcher| {
+        notify::Watcher::watch(
+            &mut watcher,
+            content_dir(),
+            notify::RecursiveMode::Recursive,
+        )?;
+        Ok(watcher)
+    });
+
+    match watcher {
+        Ok(watcher) => *WATCHER.lock().unwrap_or_else(|err| err.into_inner()) = Some(watcher),
+        Err(err) => log::warn!("Failed to watch the content directory: {err}"),
+    }
+}
+
+/// Content file that is re-read from disk after each change of the content directory.
+pub struct Reload<T> {
+    path: &'static str,
+    parse: super::loading::Parse<T>,
+    /// Generation at which the file was last read, or `None` if it has not been read yet
+    generation: Option<u64>,
+    /// Source that was last read
+    source: Option<String>,
+}
+
+impl<T> Reload<T> {
+    pub fn new(path: &'static str, parse: super::loading::Parse<T>) -> Self {
+        Self {
+            path,
+            parse,
+            generation: None,
+            source: None,
+        }
+    }
+
+    /// New value of the content if its file changed since the last poll.
+    ///
+    /// The file is also read on the first poll, so pages that are constructed after a change
+    /// do not show the content that was embedded at build time.
+    pub fn poll(&mut self, ctx: &egui::Context) -> Option<T> {
+        let generation = GENERATION.load(Ordering::Relaxed);
+        if self.generation == Some(generation) {
+            return None;
+        }
+        self.generation = Some(generation);
+
+        let path: PathBuf = content_dir().join(self.path);
+        let source = match std::fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(err) => {
+                set_error(
+                    ctx,
+                    self.path,
+                    Some(format!("content/{}: {err}", self.path)),
+                );
+                return None;
+            }
+        };
+        if self.source.as_ref() == Some(&source) {
+            return None;
+        }
+
+        let value = match (self.parse)(&source) {
+            Ok(value) => {
+                set_error(ctx, self.path, None);
+                Some(value)
+            }
+            Err(err) => {
+                set_error(ctx, self.path, Some(format!("content/{}:{err}", self.path)));
+                None
+            }
+        };
+        self.source = Some(source);
+        value
+    }
+}
+
+type Errors = std::collections::BTreeMap<&'static str, String>;
+
+fn errors_id() -> egui::Id {
+    egui::Id::new("hot_reload_errors")
+}
+
+fn set_error(ctx: &egui::Context, path: &'static str, error: Option<String>) {
+    ctx.data_mut(|data| {
+        let errors = data.get_temp_mut_or_default::<Errors>(errors_id());
+        match error {
+            Some(error) => {
+                log::warn!("Failed to reload {error}");
+                errors.insert(path, error);
+            }
+            None => {
+                errors.remove(path);
+            }
+        }
+    });
+}
+
+/// Show the errors of the content that failed to reload in an overlay.
+pub fn show_errors(ctx: &egui::Context) {
+    let errors = ctx.data(|data| data.get_temp::<Errors>(errors_id()));
+    let Some(errors) = errors.filter(|errors| !errors.is_empty()) else {
+        return;
+    };
+
+    egui::Area::new(errors_id())
+        .order(egui::Order::Foreground)
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -48.0))
+        .default_width(ctx.screen_rect().width().min(720.0) - 32.0)
+        .show(ctx, |ui| {
+            super::egui::error_card(ui, "Failed to reload the content", |ui| {
+                for error in errors.values() {
+                    ui.monospace(error);
+                }
+            });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reload_once_per_change() {
+        let ctx = egui::Context::default();
+        let mut reload = Reload::new("research.md", |src| Ok(src.len()));
+        assert!(reload.poll(&ctx).is_some());
+        assert!(reload.poll(&ctx).is_none());
+
+        // The source is unchanged, so the content is not replaced
+        GENERATION.fetch_add(1, Ordering::Relaxed);
+        assert!(reload.poll(&ctx).is_none());
+
+        let mut missing = Reload::new("missing.md", |src| Ok(src.len()));
+        assert!(missing.poll(&ctx).is_none());
+        assert!(ctx
+            .data(|data| data.get_temp::<Errors>(errors_id()))
+            .is_some_and(|errors| errors.contains_key("missing.md")));
+    }
+}
@@ -0,0 +1,257 @@
+//! Content that is embedded in the app or, with the `remote-content` feature, fetched from the
+//! server at runtime.
+//!
+//! Fetched files are cached in the persisted memory, so that returning visitors see the last
+//! known content immediately while it is revalidated in the background. Embedded content is
+//! reloaded from disk in debug builds of the native app (see [`super::hot_reload`]).
+
+/// Parser of a content file that is loaded at runtime.
+#[cfg(any(hot_reload, feature = "remote-content"))]
+pub type Parse<T> = fn(&str) -> Result<T, crate::content::parse::ParseError>;
+
+/// Content of a page that might still be loading.
+#[must_use = "You should call .show()"]
+pub struct Loadable<T> {
+    state: State<T>,
+    #[cfg(feature = "remote-content")]
+    remote: Option<remote::Remote<T>>,
+    #[cfg(hot_reload)]
+    reload: Option<super::hot_reload::Reload<T>>,
+}
+
+enum State<T> {
+    Ready(T),
+    #[cfg(feature = "remote-content")]
+    Loading,
+    #[cfg(feature = "remote-content")]
+    Failed(LoadError),
+}
+
+/// Reason why content that is fetched from the server cannot be shown.
+#[cfg(feature = "remote-content")]
+#[derive(Debug, Clone)]
+pub enum LoadError {
+    /// The request failed, e.g. because the app is offline
+    Request { path: &'static str, message: String },
+    /// The fetched file is malformed
+    Parse {
+        path: &'static str,
+        error: crate::content::parse::ParseError,
+    },
+}
+
+#[cfg(feature = "remote-content")]
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Request { path, message } => write!(f, "content/{path}: {message}"),
+            Self::Parse { path, error } => write!(f, "content/{path}:{error}"),
+        }
+    }
+}
+
+#[cfg(feature = "remote-content")]
+impl std::error::Error for LoadError {}
+
+impl<T> Loadable<T> {
+    /// Content that is available right away, e.g. because it is embedded in the app.
+    #[cfg_attr(feature = "remote-content", allow(dead_code))]
+    pub fn ready(value: T) -> Self {
+        Self {
+            state: State::Ready(value),
+            #[cfg(feature = "remote-content")]
+            remote: None,
+            #[cfg(hot_reload)]
+            reload: None,
+        }
+    }
+
+    /// Replace the content whenever the file at the given path within `content/` changes.
+    #[cfg(hot_reload)]
+    pub fn with_hot_reload(mut self, path: &'static str, parse: Parse<T>) -> Self {
+        self.reload = Some(super::hot_reload::Reload::new(path, parse));
+        self
+    }
+
+    /// Content that is parsed from the file at the given path within `content/` once it is
+    /// fetched from the server.
+    #[cfg(feature = "remote-content")]
+    pub fn fetch(path: &'static str, parse: Parse<T>) -> Self {
+        Self {
+            state: State::Loading,
+            remote: Some(remote::Remote::new(path, parse)),
+        }
+    }
+
+    /// Show the content once it is available, or otherwise its loading or error state.
+    pub fn show<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        add_contents: impl FnOnce(&mut egui::Ui, &mut T) -> R,
+    ) -> Option<R> {
+        #[cfg(feature = "remote-content")]
+        if let Some(remote) = &mut self.remote {
+            remote.poll(ui.ctx(), &mut self.state);
+        }
+        #[cfg(hot_reload)]
+        if let Some(value) = self
+            .reload
+            .as_mut()
+            .and_then(|reload| reload.poll(ui.ctx()))
+        {
+            self.state = State::Ready(value);
+        }
+
+        match &mut self.state {
+            State::Ready(value) => Some(add_contents(ui, value)),
+            #[cfg(feature = "remote-content")]
+            State::Loading => {
+                ui.vertical_centered(|ui| ui.spinner());
+                None
+            }
+            #[cfg(feature = "remote-content")]
+            State::Failed(err) => {
+                let retry =
+                    crate::utils::egui::error_card(ui, "Failed to load the content", |ui| {
+                        ui.monospace(err.to_string());
+                        ui.button("Retry").clicked()
+                    })
+                    .inner;
+                if retry {
+                    self.state = State::Loading;
+                    if let Some(remote) = &mut self.remote {
+                        remote.retry();
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Image at the given path within `content/` that is loaded at runtime.
+#[cfg(any(hot_reload, feature = "remote-content"))]
+pub fn content_image(path: &str) -> egui::ImageSource<'static> {
+    #[cfg(hot_reload)]
+    let uri = super::hot_reload::file_uri(&super::hot_reload::content_dir().join(path));
+    #[cfg(feature = "remote-content")]
+    let uri = content_url(path);
+    egui::ImageSource::Uri(uri.into())
+}
+
+/// URL of the file at the given path within `content/` on the server.
+///
+/// The content is served next to the app by default, but a different location can be set via
+/// the `CONTENT_URL` environment variable at build time (e.g. a local static file server).
+#[cfg(feature = "remote-content")]
+pub fn content_url(path: &str) -> String {
+    let base = option_env!("CONTENT_URL").map_or_else(
+        || {
+            #[cfg(target_arch = "wasm32")]
+            let origin = web_sys::window()
+                .and_then(|window| window.location().origin().ok())
+                .unwrap_or_else(|| env!("CARGO_PKG_HOMEPAGE").to_owned());
+            #[cfg(not(target_arch = "wasm32"))]
+            let origin = env!("CARGO_PKG_HOMEPAGE");
+            format!("{origin}/content")
+        },
+        str::to_owned,
+    );
+    format!("{}/{path}", base.trim_end_matches('/'))
+}
+
+#[cfg(feature = "remote-content")]
+mod remote {
+    use std::sync::Arc;
+
+    use egui::mutex::Mutex;
+
+    use super::{LoadError, Parse, State};
+
+    type Response = Arc<Mutex<Option<Result<String, String>>>>;
+
+    pub struct Remote<T> {
+        path: &'static str,
+        parse: Parse<T>,
+        /// Response of the sent request, or `None` if no request was sent yet
+        response: Option<Response>,
+    }
+
+    impl<T> Remote<T> {
+        pub fn new(path: &'static str, parse: Parse<T>) -> Self {
+            Self {
+                path,
+                parse,
+                response: None,
+            }
+        }
+
+        pub fn retry(&mut self) {
+            self.response = None;
+        }
+
+        fn cache_id(&self) -> egui::Id {
+            egui::Id::new(("remote_content", self.path))
+        }
+
+        pub fn poll(&mut self, ctx: &egui::Context, state: &mut State<T>) {
+            let cached = || ctx.data_mut(|data| data.get_persisted::<String>(self.cache_id()));
+
+            let Some(response) = &self.response else {
+                // Show the cached content until the request completes
+                if let Some(Ok(value)) = cached().as_deref().map(self.parse) {
+                    *state = State::Ready(value);
+                }
+                self.response = Some(self.send(ctx));
+                return;
+            };
+            let Some(result) = response.lock().take() else {
+                return;
+            };
+
+            let path = self.path;
+            let result = result
+                .map_err(|message| LoadError::Request { path, message })
+                .and_then(|text| {
+                    if matches!(state, State::Ready(_)) && cached().as_ref() == Some(&text) {
+                        return Ok(None);
+                    }
+                    let value =
+                        (self.parse)(&text).map_err(|error| LoadError::Parse { path, error })?;
+                    ctx.data_mut(|data| data.insert_persisted(self.cache_id(), text));
+                    Ok(Some(value))
+                });
+            match result {
+                Ok(Some(value)) => *state = State::Ready(value),
+                Ok(None) => {}
+                Err(err) => {
+                    if matches!(state, State::Ready(_)) {
+                        log::warn!("Keeping the cached content after failing to load {err}");
+                    } else {
+                        *state = State::Failed(err);
+                    }
+                }
+            }
+        }
+
+        fn send(&self, ctx: &egui::Context) -> Response {
+            let response = Response::default();
+            let request = ehttp::Request::get(super::content_url(self.path));
+            let (ctx, slot) = (ctx.clone(), Arc::clone(&response));
+            ehttp::fetch(request, move |result| {
+                let result = result.and_then(|response| {
+                    if !response.ok {
+                        return Err(format!("{} {}", response.status, response.status_text));
+                    }
+                    response
+                        .text()
+                        .map(str::to_owned)
+                        .ok_or_else(|| "The response is not valid UTF-8".to_owned())
+                });
+                *slot.lock() = Some(result);
+                ctx.request_repaint();
+            });
+            response
+        }
+    }
+}
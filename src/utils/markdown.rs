@@ -0,0 +1,325 @@
+use crate::content::Markdown;
+
+/// Minimum number of headings for which the table of contents is shown.
+const MIN_HEADINGS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, typed_builder::TypedBuilder)]
+pub struct MarkdownDocumentConfig {
+    /// Minimum width of the margin beside the content for the table of contents to be shown in
+    /// it (otherwise it is collapsed above the content)
+    #[builder(default = 192.0)]
+    pub min_sidebar_width: f32,
+    /// Maximum width of the table of contents beside the content
+    #[builder(default = 256.0)]
+    pub max_sidebar_width: f32,
+    /// Indentation of nested headings in the table of contents
+    #[builder(default = 12.0)]
+    pub heading_indent: f32,
+    /// Fraction of the visible area from its top at which a section is considered to be in view
+    #[builder(default = 0.25)]
+    pub scroll_spy_offset: f32,
+}
+
+impl Default for MarkdownDocumentConfig {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Markdown document with a table of contents of its headings.
+#[must_use = "You should call .show()"]
+pub struct MarkdownDocument {
+    pub cfg: MarkdownDocumentConfig,
+    id: egui::Id,
+    document: Markdown,
+    commonmark_cache: egui_commonmark::CommonMarkCache,
+    /// Index of the heading whose section is currently in view
+    active_heading: Option<usize>,
+    /// Index of the heading that should be scrolled into view
+    scroll_to_heading: Option<usize>,
+    /// Position of each heading below the top of the document, once it was painted
+    heading_tops: Vec<Option<f32>>,
+    /// Width and height of the document when the positions of the headings were located
+    layout_size: egui::Vec2,
+}
+
+impl MarkdownDocument {
+    pub fn new(source: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self::new_with_cfg(MarkdownDocumentConfig::default(), source)
+    }
+
+    pub fn new_with_cfg(
+        cfg: MarkdownDocumentConfig,
+        source: impl Into<std::borrow::Cow<'static, str>>,
+    ) -> Self {
+        let document = Markdown::new(source);
+        Self {
+            cfg,
+            id: egui::Id::new(document.source()),
+            document,
+            commonmark_cache: egui_commonmark::CommonMarkCache::default(),
+            active_heading: None,
+            scroll_to_heading: None,
+            heading_tops: Vec::new(),
+            layout_size: egui::Vec2::ZERO,
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        ui.vertical(|ui| {
+            if self.document.headings().len() < MIN_HEADINGS {
+                egui_commonmark::CommonMarkViewer::new().show(
+                    ui,
+                    &mut self.commonmark_cache,
+                    self.document.source(),
+                );
+                return;
+            }
+
+            // Show the table of contents beside the content if there is enough space for it
+            let clip_rect = ui.clip_rect();
+            let sidebar_width =
+                (ui.max_rect().left() - clip_rect.left() - 2.0 * ui.spacing().item_spacing.x)
+                    .min(self.cfg.max_sidebar_width);
+            let show_sidebar = sidebar_width >= self.cfg.min_sidebar_width;
+            if !show_sidebar {
+                egui::CollapsingHeader::new("Contents")
+                    .id_salt(self.id.with("contents"))
+                    .show(ui, |ui| self.show_contents(ui));
+            }
+
+            // The whole document is rendered at once so that references between its sections
+            // resolve, and its headings are then located among the painted shapes
+            let top = ui.cursor().top();
+            let width = ui.available_width();
+            let first_shape = ui.ctx().graphics(|graphics| {
+                graphics
+                    .get(ui.layer_id())
+                    .map_or(egui::layers::ShapeIdx(0), |shapes| shapes.next_idx())
+            });
+            egui_commonmark::CommonMarkViewer::new().show(
+                ui,
+                &mut self.commonmark_cache,
+                self.document.source(),
+            );
+            let located = self.locate_headings(ui, first_shape);
+
+            // Only the headings in view are painted, so the others keep their last position
+            // until the layout changes (e.g. when an image is loaded)
+            let layout_size = egui::vec2(width, ui.cursor().top() - top);
+            if layout_size != self.layout_size {
+                self.layout_size = layout_size;
+                self.heading_tops = vec![None; self.document.headings().len()];
+            }
+            for &(i, heading_top) in &located {
+                self.heading_tops[i] = Some(heading_top - top);
+            }
+
+            if let Some(i) = self.scroll_to_heading {
+                match self.heading_tops[i] {
+                    Some(heading_top) => {
+                        let heading_top = top + heading_top;
+                        ui.scroll_to_rect(
+                            egui::Rect::from_x_y_ranges(
+                                ui.max_rect().x_range(),
+                                heading_top..=heading_top,
+                            ),
+                            Some(egui::Align::TOP),
+                        );
+                        self.scroll_to_heading = None;
+                    }
+                    None => {
+                        // Scroll towards a heading that was not painted yet a screen at a time,
+                        // unless the end of the document is already in view
+                        let is_below = located.last().is_none_or(|&(last, _)| i > last);
+                        if (is_below && top + layout_size.y > clip_rect.bottom())
+                            || (!is_below && top < clip_rect.top())
+                        {
+                            let delta = if is_below { -1.0 } else { 1.0 } * clip_rect.height();
+                            ui.scroll_with_delta(egui::vec2(0.0, delta));
+                            ui.ctx().request_repaint();
+                        } else {
+                            self.scroll_to_heading = None;
+                        }
+                    }
+                }
+            }
+
+            let spy_line = clip_rect.top() + self.cfg.scroll_spy_offset * clip_rect.height();
+            let located_tops = self
+                .heading_tops
+                .iter()
+                .enumerate()
+                .filter_map(|(i, heading_top)| Some((i, top + (*heading_top)?)))
+                .collect::<Vec<_>>();
+            self.active_heading = located_tops
+                .iter()
+                .take_while(|&&(_, heading_top)| heading_top <= spy_line)
+                .chain(located_tops.first())
+                .map(|&(i, _)| i)
+                .max();
+
+            // Keep the table of contents in place while any part of the document is in view
+            let document_rect =
+                egui::Rect::from_x_y_ranges(ui.max_rect().x_range(), top..=top + layout_size.y);
+            if show_sidebar && document_rect.intersects(clip_rect) {
+                let opacity = ui.opacity();
+                let margin = ui.spacing().item_spacing;
+                egui::Area::new(self.id.with("sidebar"))
+                    .fixed_pos(clip_rect.left_top() + margin)
+                    .show(ui.ctx(), |ui| {
+                        ui.multiply_opacity(opacity);
+                        ui.set_max_width(sidebar_width);
+                        ui.label(egui::RichText::new("Contents").weak());
+                        self.show_contents(ui);
+                    });
+            }
+        });
+    }
+
+    /// Headings of the document with their tops among the shapes painted since the given one.
+    ///
+    /// Headings are the only text that is larger than the body text. A heading might be painted
+    /// in several parts (e.g. with inline code), which are matched against the titles.
+    fn locate_headings(
+        &self,
+        ui: &egui::Ui,
+        first_shape: egui::layers::ShapeIdx,
+    ) -> Vec<(usize, f32)> {
+        let body_size = egui::TextStyle::Body.resolve(ui.style()).size;
+        let is_heading = |text: &egui::epaint::TextShape| {
+            text.galley
+                .job
+                .sections
+                .first()
+                .is_some_and(|section| section.format.font_id.size > body_size)
+        };
+        let parts = ui.ctx().graphics(|graphics| {
+            graphics.get(ui.layer_id()).map_or_else(Vec::new, |shapes| {
+                shapes
+                    .all_entries()
+                    .skip(first_shape.0)
+                    .filter_map(|shape| match &shape.shape {
+                        egui::Shape::Text(text) if is_heading(text) => Some((
+                            text.visual_bounding_rect().top(),
+                            compact(text.galley.text()),
+                        )),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+        });
+
+        // The painted headings are consecutive, but not necessarily the first ones
+        let titles = self
+            .document
+            .headings()
+            .iter()
+            .map(|heading| compact(&heading.title))
+            .collect::<Vec<_>>();
+        (0..titles.len())
+            .find_map(|first| {
+                let tops = match_titles(&parts, &titles[first..])?;
+                Some(
+                    tops.into_iter()
+                        .enumerate()
+                        .map(|(i, top)| (first + i, top))
+                        .collect(),
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    fn show_contents(&mut self, ui: &mut egui::Ui) {
+        let min_level = self
+            .document
+            .headings()
+            .iter()
+            .map(|heading| heading.level)
+            .min()
+            .unwrap_or_default();
+        let mut clicked = None;
+        for (i, heading) in self.document.headings().iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.add_space((heading.level - min_level) as f32 * self.cfg.heading_indent);
+                if ui
+                    .selectable_label(self.active_heading == Some(i), &heading.title)
+                    .clicked()
+                {
+                    clicked = Some(i);
+                }
+            });
+        }
+        if clicked.is_some() {
+            self.scroll_to_heading = clicked;
+            ui.ctx().request_repaint();
+        }
+    }
+}
+
+/// Tops of the titles that the painted parts spell out in order, or `None` if they do not.
+///
+/// Parts of a title that are painted as smaller text (e.g. inline code) are missing.
+fn match_titles(parts: &[(f32, String)], titles: &[String]) -> Option<Vec<f32>> {
+    let mut titles = titles.iter();
+    let mut tops = Vec::new();
+    let mut rest: Option<&str> = None;
+    for (top, text) in parts {
+        rest = match rest.and_then(|rest| rest.strip_prefix(text.as_str())) {
+            Some(rest) => Some(rest),
+            None => {
+                tops.push(*top);
+                Some(titles.next()?.strip_prefix(text.as_str())?)
+            }
+        };
+    }
+    Some(tops)
+}
+
+/// Text without whitespace, which differs between the source and the painted text.
+fn compact(text: &str) -> String {
+    text.split_whitespace().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_headings() {
+        let mut document = MarkdownDocument::new(
+            "Intro with a [reference]\n\n# First `code`\n\nText\n\n## Nested\n\nMore text\n\n\
+             ## Last\n\n[reference]: https://example.com\n",
+        );
+        let ctx = egui::Context::default();
+        let input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(1024.0, 4096.0),
+            )),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| document.show(ui));
+        });
+
+        let tops = document
+            .heading_tops
+            .iter()
+            .copied()
+            .collect::<Option<Vec<_>>>();
+        assert!(tops.unwrap().is_sorted_by(|a, b| a < b));
+
+        // Only some of the headings might be in view
+        let titles = ["Firstcode", "Nested", "Last"].map(str::to_owned);
+        let parts = [(1.0, "First".to_owned()), (2.0, "Nested".to_owned())];
+        assert_eq!(match_titles(&parts, &titles), Some(vec![1.0, 2.0]));
+        let parts = [
+            (1.0, "Nes".to_owned()),
+            (1.0, "ted".to_owned()),
+            (2.0, "Last".to_owned()),
+        ];
+        assert_eq!(match_titles(&parts, &titles[1..]), Some(vec![1.0, 2.0]));
+        assert_eq!(match_titles(&parts, &titles), None);
+    }
+}
@@ -1 +1,8 @@
+#[cfg(feature = "bibliography")]
+pub mod download;
 pub mod egui;
+pub mod fuzzy;
+#[cfg(hot_reload)]
+pub mod hot_reload;
+pub mod loading;
+pub mod markdown;